@@ -0,0 +1,230 @@
+use alloc::{borrow::ToOwned, format, string::String};
+use core::{fmt, str::FromStr};
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Input doesn't follow the puzzle's format. Line and column are 1-based, column is in chars
+    Parse {
+        line: usize,
+        column: usize,
+        /// Offending part of the input, empty if something is missing at the end
+        text: String,
+        reason: String,
+    },
+    /// Input is well-formed, but describes something impossible, e.g. a crate taken from an
+    /// empty stack
+    InvalidState(String),
+    /// Input is valid, but has no answer, e.g. a stream without a packet marker
+    Unsolvable(String),
+    /// Input couldn't be read, e.g. it is not UTF-8
+    Read(String),
+}
+
+/// 1-based line and column of `text` in `input`, `None` if `text` is not a slice of `input`.
+fn locate(input: &str, text: &str) -> Option<(usize, usize)> {
+    let offset = (text.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    let before = input.get(..offset)?;
+
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+
+    Some((line, column))
+}
+
+impl Error {
+    /// Parse error at `text`, which must be a slice of `input` (e.g. a token of one of its lines),
+    /// so the line and column are found by its position.
+    pub fn at(input: &str, text: &str, reason: impl Into<String>) -> Self {
+        debug_assert!(
+            locate(input, text).is_some(),
+            "{text:?} is not a slice of the input"
+        );
+        let (line, column) = locate(input, text).unwrap_or((1, 1));
+
+        Self::Parse {
+            line,
+            column,
+            text: text.to_owned(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Moves the location of a parse error made for `part` of `input` alone, e.g. by `FromStr`
+    /// of a single line, to be relative to the whole `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let Self::Parse {
+            line,
+            column,
+            text,
+            reason,
+        } = self
+        else {
+            return self;
+        };
+        let (part_line, part_column) = locate(input, part).unwrap_or((1, 1));
+
+        Self::Parse {
+            line: part_line + line - 1,
+            column: if line == 1 {
+                part_column + column - 1
+            } else {
+                column
+            },
+            text,
+            reason,
+        }
+    }
+
+    /// Moves the location of a parse error `n_lines` lines down, e.g. from a line parsed alone
+    /// to the place of that line in the input.
+    #[cfg(feature = "std")]
+    pub(crate) fn below(self, n_lines: usize) -> Self {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                text,
+                reason,
+            } => Self::Parse {
+                line: line + n_lines,
+                column,
+                text,
+                reason,
+            },
+            err => err,
+        }
+    }
+
+    pub fn invalid_state(reason: impl Into<String>) -> Self {
+        Self::InvalidState(reason.into())
+    }
+
+    pub fn unsolvable(reason: impl Into<String>) -> Self {
+        Self::Unsolvable(reason.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                text,
+                reason,
+            } => {
+                write!(f, "line {line}, column {column}: {reason}")?;
+                if !text.is_empty() {
+                    write!(f, ": {text:?}")?;
+                }
+
+                Ok(())
+            }
+            Self::InvalidState(reason) => write!(f, "invalid puzzle state: {reason}"),
+            Self::Unsolvable(reason) => write!(f, "unsolvable input: {reason}"),
+            Self::Read(reason) => write!(f, "failed to read input: {reason}"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Read(err.to_string())
+    }
+}
+
+/// Parses `text`, a slice of `input`, as a number, failing with a parse error located at it.
+pub fn number<T>(input: &str, text: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.parse()
+        .map_err(|err| Error::at(input, text, format!("invalid number ({err})")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "noop\naddx 1ö\naddx ?";
+
+        assert_eq!(
+            Error::at(input, &input[0..4], "unknown"),
+            Error::Parse {
+                line: 1,
+                column: 1,
+                text: "noop".to_owned(),
+                reason: "unknown".to_owned(),
+            }
+        );
+        assert_eq!(
+            Error::at(input, &input[19..20], "not a number").to_string(),
+            r#"line 3, column 6: not a number: "?""#
+        );
+        // columns are in chars
+        assert!(matches!(
+            Error::at(input, &input[input.len()..], "no argument"),
+            Error::Parse {
+                line: 3,
+                column: 7,
+                ..
+            }
+        ));
+        assert!(matches!(
+            Error::at(input, &input[5..], "?"),
+            Error::Parse {
+                line: 2,
+                column: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_within() {
+        let input = "a\nbb cc\ndd";
+        let part = &input[5..];
+        let err = Error::at(part, &part[..1], "first");
+        assert!(matches!(
+            err.within(input, part),
+            Error::Parse {
+                line: 2,
+                column: 4,
+                ..
+            }
+        ));
+
+        let err = Error::at(part, &part[3..], "second line");
+        assert!(matches!(
+            err.within(input, part),
+            Error::Parse {
+                line: 3,
+                column: 1,
+                ..
+            }
+        ));
+
+        let err = Error::unsolvable("no answer");
+        assert_eq!(err.clone().within(input, part), err);
+    }
+
+    #[test]
+    fn test_number() {
+        let input = "12 x";
+
+        assert_eq!(number::<u8>(input, &input[..2]), Ok(12));
+        assert_eq!(
+            number::<u8>(input, &input[3..]).unwrap_err().to_string(),
+            r#"line 1, column 4: invalid number (invalid digit found in string): "x""#
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Shared by all the days: [`Error`] of parsing and solving, toolkit for parsing the inputs in
+//! [`parse`] (or reading them line by line in [`read`]), fallible solving itself with
+//! [`TrySolve`], and opt-in [`trace`] of the steps of solving.
+//!
+//! Everything but [`read`] works without `std` (with `alloc`), so do the cores of the days: `std`
+//! feature (on by default) adds reading inputs, sinks of [`trace`] and conversions from
+//! `advent_utils::Part`.
+//!
+//! `FromStr` of every `Solution` has `Box<dyn std::error::Error>` as its error type, as
+//! `advent_utils::Solver` expects, but the boxed error is always an [`Error`]:
+//!
+//! ```
+//! use common::Error;
+//!
+//! let input = "1\n2\nthree\n";
+//! let err = common::number::<u32>(input, &input[4..9]).unwrap_err();
+//!
+//! assert!(matches!(err, Error::Parse { line: 3, column: 1, .. }));
+//! assert_eq!(
+//!     err.to_string(),
+//!     r#"line 3, column 1: invalid number (invalid digit found in string): "three""#
+//! );
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod error;
+pub mod parse;
+#[cfg(feature = "std")]
+pub mod read;
+mod solve;
+pub mod trace;
+
+pub use error::{number, Error, Result};
+#[cfg(feature = "std")]
+pub use read::FromReader;
+pub use solve::{Answer, Part, TrySolve};
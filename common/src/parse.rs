@@ -0,0 +1,264 @@
+//! Small toolkit for parsing puzzle inputs: splitting into records and lines, matching literals
+//! and tagged lines, numbers and lists of them.
+//!
+//! Every piece is an [`Input`], a slice which remembers the whole input, so failures are
+//! [`Error::Parse`] located at the offending text without any bookkeeping:
+//!
+//! ```
+//! use common::parse::Input;
+//!
+//! let input = Input::new("move 1 from 2 to 1\nmove 3 frm 1 to 3\n");
+//! let mut lines = input.lines();
+//! let first = lines.next().unwrap();
+//! let (n, rest) = first.literal("move ")?.split_once(" ")?;
+//! assert_eq!(n.number::<u32>()?, 1);
+//! assert_eq!(rest.as_str(), "from 2 to 1");
+//!
+//! let second = lines.next().unwrap();
+//! let (_, rest) = second.literal("move ")?.split_once(" ")?;
+//! assert_eq!(
+//!     rest.literal("from ").unwrap_err().to_string(),
+//!     r#"line 2, column 8: expected "from ": "frm 1 to 3""#
+//! );
+//! # Ok::<(), common::Error>(())
+//! ```
+
+use alloc::{format, string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use crate::{Error, Result};
+
+/// Part of the puzzle input, which locates errors in the whole of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    whole: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Input<'a> {
+    /// The whole input.
+    pub fn new(whole: &'a str) -> Self {
+        Self { whole, text: whole }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Error at this text, or at its first line if there are several.
+    pub fn error(&self, reason: impl Into<String>) -> Error {
+        let first_line = self.text.lines().next().unwrap_or(self.text);
+
+        Error::at(self.whole, first_line, reason)
+    }
+
+    /// Error right after this text, for something missing.
+    pub fn error_at_end(&self, reason: impl Into<String>) -> Error {
+        Error::at(self.whole, &self.text[self.text.len()..], reason)
+    }
+
+    /// Records separated by `separator`, e.g. blocks between empty lines with `"\n\n"`.
+    pub fn records(self, separator: &'a str) -> impl Iterator<Item = Self> {
+        self.text.split(separator).map(move |text| self.slice(text))
+    }
+
+    pub fn lines(self) -> Lines<'a> {
+        Lines {
+            input: self,
+            lines: self.text.lines(),
+        }
+    }
+
+    /// Every character with its own one-character input.
+    pub fn chars(self) -> impl Iterator<Item = (char, Self)> {
+        self.text
+            .char_indices()
+            .map(move |(i, c)| (c, self.slice(&self.text[i..i + c.len_utf8()])))
+    }
+
+    /// Parts before and after byte index `mid`, which must be on a char boundary.
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        let (left, right) = self.text.split_at(mid);
+
+        (self.slice(left), self.slice(right))
+    }
+
+    /// Parts before and after the first `delimiter`.
+    pub fn split_once(self, delimiter: &str) -> Result<(Self, Self)> {
+        let (left, right) = self
+            .text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(format!("no {delimiter:?} delimiter")))?;
+
+        Ok((self.slice(left), self.slice(right)))
+    }
+
+    /// The rest after `prefix`, which the text must start with.
+    pub fn literal(self, prefix: &str) -> Result<Self> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.slice(rest)),
+            None => Err(self.error(format!("expected {prefix:?}"))),
+        }
+    }
+
+    /// The whole text as a number.
+    pub fn number<T>(self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        crate::number(self.whole, self.text)
+    }
+
+    /// Numbers separated by `separator`, e.g. `79, 98` with `", "`.
+    pub fn numbers<T>(self, separator: &'a str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.records(separator).map(Self::number).collect()
+    }
+
+    /// Fails with `reason` unless the text is empty.
+    pub fn end(self, reason: impl Into<String>) -> Result<()> {
+        match self.text.is_empty() {
+            true => Ok(()),
+            false => Err(self.error(reason)),
+        }
+    }
+
+    fn slice(&self, text: &'a str) -> Self {
+        Self {
+            whole: self.whole,
+            text,
+        }
+    }
+}
+
+impl fmt::Display for Input<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// Lines of an [`Input`], which may be taken one by one by their tags.
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    input: Input<'a>,
+    lines: core::str::Lines<'a>,
+}
+
+impl<'a> Lines<'a> {
+    /// The rest of the next line after `tag`, e.g. `79, 98` of `Starting items: 79, 98`.
+    pub fn tagged(&mut self, tag: &str) -> Result<Input<'a>> {
+        self.next()
+            .ok_or_else(|| {
+                self.input
+                    .error_at_end(format!("no line starting with {tag:?}"))
+            })?
+            .literal(tag)
+    }
+
+    /// Fails if there are lines left, except for empty ones.
+    pub fn end(mut self) -> Result<()> {
+        match self.find(|line| !line.is_empty()) {
+            Some(line) => Err(line.error("unexpected line")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Input<'a>;
+
+    fn next(&mut self) -> Option<Input<'a>> {
+        let line = self.lines.next()?;
+
+        Some(self.input.slice(line))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Lines<'a> {
+    fn next_back(&mut self) -> Option<Input<'a>> {
+        let line = self.lines.next_back()?;
+
+        Some(self.input.slice(line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_located() {
+        let input = Input::new("a 1\n\nb 2, 3, x\n");
+        let records: Vec<_> = input.records("\n\n").collect();
+        assert_eq!(records.len(), 2);
+
+        let line = records[1].lines().next().unwrap();
+        let (tag, numbers) = line.split_once(" ").unwrap();
+        assert_eq!(tag.as_str(), "b");
+        assert_eq!(
+            numbers.numbers::<u8>(", ").unwrap_err().to_string(),
+            r#"line 3, column 9: invalid number (invalid digit found in string): "x""#
+        );
+        assert_eq!(
+            tag.split_once(",").unwrap_err().to_string(),
+            r#"line 3, column 1: no "," delimiter: "b""#
+        );
+        // multi-line text is reported by its first line
+        assert_eq!(
+            input.literal("b").unwrap_err().to_string(),
+            r#"line 1, column 1: expected "b": "a 1""#
+        );
+        assert_eq!(
+            records[0].error_at_end("missing").to_string(),
+            "line 1, column 4: missing"
+        );
+    }
+
+    #[test]
+    fn test_chars() {
+        let input = Input::new("ab\ncä");
+        let (c, at) = input.lines().nth(1).unwrap().chars().nth(1).unwrap();
+
+        assert_eq!(c, 'ä');
+        assert_eq!(
+            at.error("unknown").to_string(),
+            r#"line 2, column 2: unknown: "ä""#
+        );
+    }
+
+    #[test]
+    fn test_tagged_lines() {
+        let input = Input::new("Monkey 0:\n  Items: 79, 98\n\n  Test: 23\n");
+        let mut lines = input.lines();
+
+        assert_eq!(lines.tagged("Monkey ").unwrap().as_str(), "0:");
+        assert_eq!(
+            lines.tagged("  Items: ").unwrap().numbers::<u32>(", "),
+            Ok(vec![79, 98])
+        );
+        assert_eq!(
+            lines.clone().tagged("  Test: ").unwrap_err().to_string(),
+            r#"line 3, column 1: expected "  Test: ""#
+        );
+        assert_eq!(
+            lines.clone().end().unwrap_err().to_string(),
+            r#"line 4, column 1: unexpected line: "  Test: 23""#
+        );
+
+        lines.next();
+        assert_eq!(lines.tagged("  Test: ").unwrap().number::<u32>(), Ok(23));
+        assert_eq!(
+            lines.clone().tagged("  If true: ").unwrap_err().to_string(),
+            "line 5, column 1: no line starting with \"  If true: \""
+        );
+        assert_eq!(lines.end(), Ok(()));
+    }
+}
@@ -0,0 +1,113 @@
+//! Reading puzzle inputs piece by piece, for the ones too large to be held in memory at once, e.g.
+//! generated ones of several gigabytes.
+//!
+//! [`lines`] hands out every line as an [`Input`] of its own, reusing a single buffer, and locates
+//! failures in the whole input just like [`Input::lines`] does:
+//!
+//! ```
+//! use common::read;
+//!
+//! let mut sum = 0u32;
+//! let err = read::lines("1\n2\nthree\n".as_bytes(), |line| {
+//!     sum += line.number::<u32>()?;
+//!
+//!     Ok(())
+//! })
+//! .unwrap_err();
+//!
+//! assert_eq!(sum, 3);
+//! assert_eq!(
+//!     err.to_string(),
+//!     r#"line 3, column 1: invalid number (invalid digit found in string): "three""#
+//! );
+//! ```
+
+use std::io::BufRead;
+
+use crate::{parse::Input, Result};
+
+/// Solutions (or summaries of them) which are read from a [`BufRead`] in memory bounded by the
+/// longest line rather than by the whole input.
+pub trait FromReader: Sized {
+    fn from_reader(reader: impl BufRead) -> Result<Self>;
+}
+
+/// Calls `f` with every line of `reader`, without the line ending, stopping at the first error.
+///
+/// Line endings are the ones of [`str::lines`], i.e. `\n` or `\r\n`.
+pub fn lines<F>(mut reader: impl BufRead, mut f: F) -> Result<()>
+where
+    F: FnMut(Input<'_>) -> Result<()>,
+{
+    let mut buffer = String::new();
+    for n_line in 0.. {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+
+        let line = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        f(Input::new(line)).map_err(|err| err.below(n_line))?;
+    }
+
+    Ok(())
+}
+
+/// Every byte of `reader`, for inputs which are a single line, e.g. a datastream.
+pub fn bytes(reader: impl BufRead) -> impl Iterator<Item = Result<u8>> {
+    reader.bytes().map(|byte| Ok(byte?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Error;
+
+    /// Lines as they are read, and the same ones split by [`Input::lines`].
+    fn both(input: &str) -> (Vec<String>, Vec<String>) {
+        let mut read = vec![];
+        lines(input.as_bytes(), |line| {
+            read.push(line.as_str().to_owned());
+
+            Ok(())
+        })
+        .unwrap();
+        let split = Input::new(input)
+            .lines()
+            .map(|line| line.as_str().to_owned())
+            .collect();
+
+        (read, split)
+    }
+
+    #[test]
+    fn test_same_as_split() {
+        for input in ["", "\n", "a", "a\nb\n", "a\r\nb\r\n\r\nc", "a\n\n\n"] {
+            let (read, split) = both(input);
+
+            assert_eq!(read, split, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "ab\ncä x";
+        let err = lines(input.as_bytes(), |line| match line.split_once(" ") {
+            Ok((_, x)) => Err(x.error("unexpected")),
+            Err(_) => Ok(()),
+        });
+        assert_eq!(err, Err(Error::at(input, &input[7..], "unexpected")));
+
+        let err = lines(&b"ok\n\xff\n"[..], |_| Ok(())).unwrap_err();
+        assert!(matches!(err, Error::Read(_)), "{err:?}");
+    }
+
+    #[test]
+    fn test_bytes() {
+        let read: Result<Vec<_>> = bytes("abc".as_bytes()).collect();
+
+        assert_eq!(read, Ok(b"abc".to_vec()));
+    }
+}
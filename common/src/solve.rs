@@ -0,0 +1,154 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{Error, Result};
+
+/// Part of a puzzle, as `advent_utils::Part` which is only available with `std`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[cfg(feature = "std")]
+impl From<advent_utils::Part> for Part {
+    fn from(part: advent_utils::Part) -> Self {
+        match part {
+            advent_utils::Part::One => Self::One,
+            advent_utils::Part::Two => Self::Two,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Part> for advent_utils::Part {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Self::One,
+            Part::Two => Self::Two,
+        }
+    }
+}
+
+/// Answer of a part, as a value rather than a sentence.
+///
+/// `Display` renders just the value (a grid with every row on its own line), the wording for
+/// humans is added by [`TrySolve::describe`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Rows of a picture, e.g. letters lit on day 10's CRT.
+    Grid(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Grid(rows) => rows.iter().try_for_each(|row| writeln!(f, "{row}")),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Self::Number(n)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Self::Number(n.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Self::Number(n.into())
+    }
+}
+
+impl TryFrom<u64> for Answer {
+    type Error = Error;
+
+    fn try_from(n: u64) -> Result<Self> {
+        i64::try_from(n)
+            .map(Self::Number)
+            .map_err(|_| Error::unsolvable(format!("answer {n} is too large")))
+    }
+}
+
+impl TryFrom<usize> for Answer {
+    type Error = Error;
+
+    fn try_from(n: usize) -> Result<Self> {
+        Self::try_from(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+/// Fallible counterpart of `advent_utils::Solver::solve`, implemented by every day.
+///
+/// `Solver::solve` of the days only renders the result for display with
+/// [`TrySolve::solve_or_error`], callers which need to tell failures apart (like `run-all`) use
+/// [`TrySolve::try_solve`].
+pub trait TrySolve {
+    fn try_solve(&self, part: Part) -> Result<Answer>;
+
+    /// The answer in a sentence, for humans only. Just the value by default.
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        let _ = part;
+
+        answer.to_string()
+    }
+
+    /// The described answer, or the error in its place.
+    fn solve_or_error(&self, part: impl Into<Part>) -> String
+    where
+        Self: Sized,
+    {
+        let part = part.into();
+
+        match self.try_solve(part) {
+            Ok(answer) => self.describe(&part, &answer),
+            Err(err) => format!("error: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-15).to_string(), "-15");
+        assert_eq!(Answer::from("CMZ".to_owned()).to_string(), "CMZ");
+        assert_eq!(
+            Answer::Grid(vec!["#..#".to_owned(), ".##.".to_owned()]).to_string(),
+            "#..#\n.##.\n"
+        );
+    }
+
+    #[test]
+    fn test_too_large() {
+        assert_eq!(Answer::try_from(42_usize), Ok(Answer::Number(42)));
+        assert_eq!(
+            Answer::try_from(u64::MAX),
+            Err(Error::unsolvable(
+                "answer 18446744073709551615 is too large"
+            ))
+        );
+    }
+}
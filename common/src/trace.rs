@@ -0,0 +1,179 @@
+//! Opt-in tracing of intermediate states: days emit step [`Event`]s (a rope after every move, a
+//! CPU cycle, a thrown item), which go to the sink installed on the current thread, if any.
+//!
+//! Without a sink an event costs a check of a thread local, its fields are never built. Without
+//! `std` there are no sinks, and events are dropped right away. Tests capture the events of a
+//! closure:
+//!
+//! ```
+//! use common::trace;
+//!
+//! let ((), events) = trace::capture(|| {
+//!     for x in [1, 2] {
+//!         trace::event("step", || vec![("x", x.to_string())]);
+//!     }
+//! });
+//!
+//! assert_eq!(events.len(), 2);
+//! assert_eq!(events[1].field("x"), Some("2"));
+//! assert_eq!(events[1].to_string(), "step: x=2");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{boxed::Box, cell::RefCell, mem, rc::Rc};
+
+/// Step of a day with the state after it, e.g. `move` with the move made and the rope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub step: &'static str,
+    /// Named values, already formatted
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl Event {
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// `step: name=value name=value`, values spanning several lines (like a rendered grid) follow on
+/// their own lines.
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.step)?;
+        for (name, value) in self
+            .fields
+            .iter()
+            .filter(|(_, value)| !value.contains('\n'))
+        {
+            write!(f, " {name}={value}")?;
+        }
+        for (name, value) in self.fields.iter().filter(|(_, value)| value.contains('\n')) {
+            write!(f, "\n{name}:\n{}", value.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+type Sink = Box<dyn FnMut(Event)>;
+
+#[cfg(feature = "std")]
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Whether events of the current thread go anywhere, for tracing which is costly to prepare.
+#[cfg(feature = "std")]
+pub fn enabled() -> bool {
+    SINK.with(|sink| sink.borrow().is_some())
+}
+
+/// Whether events go anywhere, which they never do without `std`.
+#[cfg(not(feature = "std"))]
+pub fn enabled() -> bool {
+    false
+}
+
+/// Emits the event of `step` to the sink of the current thread, `fields` are only built if there
+/// is one.
+pub fn event<F>(step: &'static str, fields: F)
+where
+    F: FnOnce() -> Vec<(&'static str, String)>,
+{
+    #[cfg(feature = "std")]
+    if enabled() {
+        // fields are built before the sink is borrowed, in case they emit events themselves
+        let event = Event {
+            step,
+            fields: fields(),
+        };
+        SINK.with(|sink| {
+            if let Some(sink) = sink.borrow_mut().as_mut() {
+                sink(event);
+            }
+        });
+    }
+
+    #[cfg(not(feature = "std"))]
+    let _ = (step, fields);
+}
+
+/// Runs `f` with `sink` receiving the events of the current thread, restoring the previous sink
+/// afterwards (even if `f` panics).
+#[cfg(feature = "std")]
+pub fn with_sink<T>(sink: impl FnMut(Event) + 'static, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Sink>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SINK.with(|sink| *sink.borrow_mut() = self.0.take());
+        }
+    }
+
+    let previous = SINK.with(|current| current.borrow_mut().replace(Box::new(sink)));
+    let _restore = Restore(previous);
+
+    f()
+}
+
+/// Runs `f`, collecting the events it emits.
+#[cfg(feature = "std")]
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+    let events = Rc::new(RefCell::new(vec![]));
+    let sink = Rc::clone(&events);
+    let result = with_sink(move |event| sink.borrow_mut().push(event), f);
+
+    let events = mem::take(&mut *events.borrow_mut());
+    (result, events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled() {
+        let mut built = false;
+        event("step", || {
+            built = true;
+
+            vec![]
+        });
+
+        assert!(!built);
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn test_nested() {
+        let ((_, inner), outer) = capture(|| {
+            event("outer", Vec::new);
+            let inner = capture(|| event("inner", Vec::new));
+            event("outer", Vec::new);
+
+            inner
+        });
+
+        assert_eq!(inner.len(), 1);
+        assert!(outer.iter().all(|event| event.step == "outer"));
+        assert_eq!(outer.len(), 2);
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn test_display() {
+        let event = Event {
+            step: "move",
+            fields: vec![("move", "R 4".to_owned()), ("rope", "H1\n.T\n".to_owned())],
+        };
+
+        assert_eq!(event.to_string(), "move: move=R 4\nrope:\nH1\n.T");
+    }
+}
@@ -0,0 +1,39 @@
+//! Reading the input line by line.
+
+use std::{error, io::BufRead, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{read, FromReader, TrySolve};
+
+use crate::{Elves, Solution};
+
+impl FromStr for Solution {
+    type Err = Box<dyn error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s)?)
+    }
+}
+
+impl FromReader for Solution {
+    fn from_reader(reader: impl BufRead) -> common::Result<Self> {
+        let mut elves = Elves::new();
+        read::lines(reader, |line| elves.line(line))?;
+
+        Ok(elves.finish())
+    }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.solve_or_error(part)
+    }
+
+    fn day_number() -> u32 {
+        1
+    }
+
+    fn implemented_parts() -> Vec<Part> {
+        vec![Part::One, Part::Two]
+    }
+}
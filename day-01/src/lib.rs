@@ -1,50 +1,99 @@
-use std::{error::Error, str::FromStr};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use advent_utils::{Part, Solver};
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{format, string::String, vec::Vec};
+
+use common::{parse::Input, Answer, Error, Part, TrySolve};
 
 #[derive(Debug)]
 pub struct Solution {
+    /// Totals of the top 3 elves (or less if there are less), in descending order
     calories_data: Vec<i64>,
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut calories_data: Vec<_> = s
-            .split("\n\n")
-            .map(|one_elf_data| {
-                one_elf_data
-                    .split('\n')
-                    .filter_map(|calories| calories.parse::<i64>().ok())
-                    .sum()
-            })
-            .collect();
-        calories_data.sort_unstable_by(|a: &i64, b: &i64| b.cmp(a));
-
-        Ok(Self { calories_data })
+impl Solution {
+    /// Elves are separated by empty lines, only the top 3 of them are kept.
+    pub fn parse(s: &str) -> common::Result<Self> {
+        let mut elves = Elves::new();
+        for line in Input::new(s).lines() {
+            elves.line(line)?;
+        }
+
+        Ok(elves.finish())
+    }
+
+    fn add_elf(&mut self, calories: i64) {
+        let at = self.calories_data.partition_point(|&top| top >= calories);
+        self.calories_data.insert(at, calories);
+        self.calories_data.truncate(3);
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
-        match part {
-            Part::One => format!(
-                "elf with most reserves has total of {} calories",
-                self.calories_data[0]
-            ),
-            Part::Two => format!(
-                "top 3 elves have total of {} calories",
-                self.calories_data[..3].iter().sum::<i64>()
-            ),
+/// Solution being built line by line.
+struct Elves {
+    solution: Solution,
+    elf: i64,
+}
+
+impl Elves {
+    fn new() -> Self {
+        Self {
+            solution: Solution {
+                calories_data: Vec::with_capacity(4),
+            },
+            elf: 0,
+        }
+    }
+
+    fn line(&mut self, calories: Input<'_>) -> common::Result<()> {
+        if calories.is_empty() {
+            self.solution.add_elf(self.elf);
+            self.elf = 0;
+        } else {
+            self.elf = self
+                .elf
+                .checked_add(calories.number()?)
+                .ok_or_else(|| calories.error("calories overflow for elf"))?;
         }
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> Solution {
+        self.solution.add_elf(self.elf);
+
+        self.solution
     }
+}
 
-    fn day_number() -> u32 {
-        1
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
+        match part {
+            Part::One => Ok(self.calories_data[0].into()),
+            Part::Two => {
+                let top_3 = self
+                    .calories_data
+                    .get(..3)
+                    .ok_or_else(|| Error::unsolvable("less than 3 elves"))?;
+
+                let total = top_3
+                    .iter()
+                    .try_fold(0i64, |total, &calories| total.checked_add(calories))
+                    .ok_or_else(|| Error::unsolvable("total calories of top 3 elves overflow"))?;
+
+                Ok(total.into())
+            }
+        }
     }
 
-    fn implemented_parts() -> Vec<Part> {
-        vec![Part::One, Part::Two]
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        match part {
+            Part::One => format!("elf with most reserves has total of {answer} calories"),
+            Part::Two => format!("top 3 elves have total of {answer} calories"),
+        }
     }
 }
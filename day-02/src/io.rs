@@ -0,0 +1,63 @@
+//! Reading the guide line by line.
+
+use std::{error, io::BufRead, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{read, FromReader, TrySolve};
+
+use crate::{Scores, Solution};
+
+impl FromStr for Solution {
+    type Err = Box<dyn error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s)?)
+    }
+}
+
+impl FromReader for Scores {
+    fn from_reader(reader: impl BufRead) -> common::Result<Self> {
+        let mut scores = Self::default();
+        read::lines(reader, |line| scores.line(line))?;
+
+        Ok(scores)
+    }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.solve_or_error(part)
+    }
+
+    fn day_number() -> u32 {
+        2
+    }
+
+    fn implemented_parts() -> Vec<Part> {
+        vec![Part::One, Part::Two]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use common::Answer;
+
+    #[test]
+    fn test_read() {
+        let scores = Scores::from_reader("A Y\nB X\nC Z\n".as_bytes()).unwrap();
+
+        assert_eq!(scores.try_solve(common::Part::One), Ok(Answer::Number(15)));
+        assert_eq!(scores.try_solve(common::Part::Two), Ok(Answer::Number(12)));
+        assert_eq!(
+            Scores::from_reader("A Y\nB Q\n".as_bytes()).unwrap_err(),
+            common::Error::Parse {
+                line: 2,
+                column: 3,
+                text: "Q".to_owned(),
+                reason: "unknown second column".to_owned(),
+            }
+        );
+    }
+}
@@ -1,6 +1,14 @@
-use std::{error::Error, str::FromStr};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use advent_utils::{Part, Solver};
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+use common::{parse::Input, Answer, Part, TrySolve};
 
 /// Rock-paper-scisors game move.
 ///
@@ -77,84 +85,136 @@ impl Move {
     }
 }
 
+/// `A Y`: opponent's move and the second column, which is either my move or the outcome.
+fn parse_round(line: Input<'_>) -> common::Result<(Move, char)> {
+    let (left, right) = line.split_once(" ")?;
+
+    let left = match left.as_str() {
+        "A" => Move::Rock,
+        "B" => Move::Paper,
+        "C" => Move::Scisors,
+        _ => return Err(left.error("unknown opponent's move")),
+    };
+    let right = match right.as_str() {
+        "X" => 'X',
+        "Y" => 'Y',
+        "Z" => 'Z',
+        _ => return Err(right.error("unknown second column")),
+    };
+
+    Ok((left, right))
+}
+
+/// Score of the round, with the second column read as my move in part one and as the outcome in
+/// part two.
+fn score(part: &Part, (other_move, column): (Move, char)) -> usize {
+    match part {
+        Part::One => {
+            let my_move = match column {
+                'X' => Move::Rock,
+                'Y' => Move::Paper,
+                'Z' => Move::Scisors,
+                _ => unreachable!(),
+            };
+
+            my_move.points() + my_move.calculate_outcome(other_move).points()
+        }
+        Part::Two => {
+            let outcome = match column {
+                'X' => Outcome::Lost,
+                'Y' => Outcome::Draw,
+                'Z' => Outcome::Won,
+                _ => unreachable!(),
+            };
+
+            outcome.points() + outcome.infere_move(other_move).points()
+        }
+    }
+}
+
+fn describe(part: &Part, answer: &Answer) -> String {
+    match part {
+        Part::One => format!("you will end up with total score of {answer}"),
+        Part::Two => format!("actually, your score will be {answer}"),
+    }
+}
+
 #[derive(Debug)]
 pub struct Solution {
     guide: Vec<(Move, char)>,
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let guide = s
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
+        let guide = Input::new(s)
             .lines()
-            .map(|line| {
-                let (left, right) = line.split_once(' ').expect("failed to split guide entry");
-                assert!(left.len() == 1 && right.len() == 1);
+            .map(parse_round)
+            .collect::<common::Result<_>>()?;
 
-                let left = match left.chars().next().unwrap() {
-                    'A' => Move::Rock,
-                    'B' => Move::Paper,
-                    'C' => Move::Scisors,
-                    _ => unreachable!(),
-                };
+        Ok(Self { guide })
+    }
+}
 
-                (left, right.chars().next().unwrap())
-            })
-            .collect();
+/// Writes the guide back in the input format.
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &(other_move, column) in &self.guide {
+            let other_move = match other_move {
+                Move::Rock => 'A',
+                Move::Paper => 'B',
+                Move::Scisors => 'C',
+            };
+            writeln!(f, "{other_move} {column}")?;
+        }
 
-        Ok(Self { guide })
+        Ok(())
     }
 }
 
-impl Solver for Solution {
-    fn day_number() -> u32 {
-        2
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
+        let total = self
+            .guide
+            .iter()
+            .map(|&round| score(&part, round))
+            .sum::<usize>();
+
+        Answer::try_from(total)
+    }
+
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
     }
+}
 
-    fn solve(&self, part: Part) -> String {
+/// Total scores of both parts, tallied while reading the guide instead of keeping it.
+#[derive(Debug, Default)]
+pub struct Scores {
+    as_moves: usize,
+    as_outcomes: usize,
+}
+
+impl Scores {
+    /// Tallies the round of the next line of the guide.
+    pub fn line(&mut self, line: Input<'_>) -> common::Result<()> {
+        let round = parse_round(line)?;
+        self.as_moves += score(&Part::One, round);
+        self.as_outcomes += score(&Part::Two, round);
+
+        Ok(())
+    }
+}
+
+impl TrySolve for Scores {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
         match part {
-            Part::One => {
-                let score = self
-                    .guide
-                    .iter()
-                    .map(|&(other_move, my_move)| {
-                        let my_move = match my_move {
-                            'X' => Move::Rock,
-                            'Y' => Move::Paper,
-                            'Z' => Move::Scisors,
-                            _ => unreachable!(),
-                        };
-
-                        my_move.points() + my_move.calculate_outcome(other_move).points()
-                    })
-                    .sum::<usize>();
-
-                format!("you will end up with total score of {}", score)
-            }
-            Part::Two => {
-                let score = self
-                    .guide
-                    .iter()
-                    .map(|&(other_move, outcome)| {
-                        let outcome = match outcome {
-                            'X' => Outcome::Lost,
-                            'Y' => Outcome::Draw,
-                            'Z' => Outcome::Won,
-                            _ => unreachable!(),
-                        };
-
-                        outcome.points() + outcome.infere_move(other_move).points()
-                    })
-                    .sum::<usize>();
-
-                format!("actually, your score will be {}", score)
-            }
+            Part::One => Answer::try_from(self.as_moves),
+            Part::Two => Answer::try_from(self.as_outcomes),
         }
     }
 
-    fn implemented_parts() -> Vec<Part> {
-        vec![Part::One, Part::Two]
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
     }
 }
 
@@ -166,12 +226,14 @@ mod tests {
     fn test_example() {
         let solution: Solution = "A Y\nB X\nC Z\n".parse().unwrap();
 
+        assert_eq!(solution.try_solve(Part::One), Ok(Answer::Number(15)));
+        assert_eq!(solution.try_solve(Part::Two), Ok(Answer::Number(12)));
         assert_eq!(
-            &solution.solve(Part::One),
+            solution.solve_or_error(Part::One),
             "you will end up with total score of 15"
         );
         assert_eq!(
-            &solution.solve(Part::Two),
+            solution.solve_or_error(Part::Two),
             "actually, your score will be 12"
         );
     }
@@ -1,6 +1,13 @@
-use std::{collections::BTreeSet, error::Error, str::FromStr};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use advent_utils::{Part, Solver};
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{collections::BTreeSet, format, string::String, vec::Vec};
+
+use common::{parse::Input, Answer, Error, Part, TrySolve};
 
 #[derive(Debug)]
 pub struct Solution {
@@ -12,60 +19,78 @@ pub struct Solution {
 /// * Lowercase item types a through z have priorities `1` through `26`.
 /// * Uppercase item types A through Z have priorities `27` through `52`.
 ///
+/// Other bytes are not item types.
+///
 /// ```
 /// use day_03::priority;
 ///
-/// assert_eq!(priority(b'a'), 1);
-/// assert_eq!(priority(b'c'), 3);
-/// assert_eq!(priority(b'z'), 26);
-/// assert_eq!(priority(b'Z'), 52);
+/// assert_eq!(priority(b'a'), Some(1));
+/// assert_eq!(priority(b'c'), Some(3));
+/// assert_eq!(priority(b'z'), Some(26));
+/// assert_eq!(priority(b'Z'), Some(52));
+/// assert_eq!(priority(b'!'), None);
 /// ```
-pub fn priority(c: u8) -> u8 {
-    if c >= b'a' {
-        c - b'a' + 1
-    } else {
-        c - b'A' + 1 + 26
+pub fn priority(c: u8) -> Option<u8> {
+    match c {
+        b'a'..=b'z' => Some(c - b'a' + 1),
+        b'A'..=b'Z' => Some(c - b'A' + 1 + 26),
+        _ => None,
     }
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let packings = s
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
+        let packings = Input::new(s)
             .lines()
-            .map(|l| l.bytes().map(priority).collect())
-            .collect();
+            .map(|l| {
+                l.chars()
+                    .map(|(c, at)| {
+                        u8::try_from(c)
+                            .ok()
+                            .and_then(priority)
+                            .ok_or_else(|| at.error("unknown item type"))
+                    })
+                    .collect()
+            })
+            .collect::<common::Result<_>>()?;
 
         Ok(Self { packings })
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
         match part {
             Part::One => {
                 let sum_errors = self
                     .packings
                     .iter()
-                    .map(|packing| {
+                    .enumerate()
+                    .map(|(i, packing)| {
                         let lower_types = BTreeSet::from_iter(&packing[0..packing.len() / 2]);
                         let upper_types = BTreeSet::from_iter(&packing[packing.len() / 2..]);
 
-                        **lower_types
+                        lower_types
                             .intersection(&upper_types)
                             .next()
-                            .expect("no types are repeating") as u32
+                            .map(|&&priority| priority as u32)
+                            .ok_or_else(|| {
+                                Error::invalid_state(format!(
+                                    "no types are repeating in rucksack {}",
+                                    i + 1
+                                ))
+                            })
                     })
-                    .sum::<u32>();
+                    .sum::<common::Result<u32>>()?;
 
-                format!("priorities sum for the mistaken types: {}", sum_errors)
+                Ok(sum_errors.into())
             }
             Part::Two => {
                 let sum_badges = self
                     .packings
                     .chunks_exact(3)
-                    .map(|chunk| {
+                    .enumerate()
+                    .map(|(i, chunk)| {
                         let set_a = BTreeSet::from_iter(&chunk[0]);
                         let set_b = BTreeSet::from_iter(&chunk[1]);
                         let set_c = BTreeSet::from_iter(&chunk[2]);
@@ -74,19 +99,27 @@ impl Solver for Solution {
 
                         let common_abc = common_ab.intersection(&set_c).collect::<Vec<_>>();
 
-                        assert_eq!(common_abc.len(), 1, "wrong number of common items in group");
-
-                        (**common_abc[0]) as u32
+                        match common_abc[..] {
+                            [&&priority] => Ok(priority as u32),
+                            _ => Err(Error::invalid_state(format!(
+                                "{} common items in group {}",
+                                common_abc.len(),
+                                i + 1
+                            ))),
+                        }
                     })
-                    .sum::<u32>();
+                    .sum::<common::Result<u32>>()?;
 
-                format!("priorities sum for the group names: {}", sum_badges)
+                Ok(sum_badges.into())
             }
         }
     }
 
-    fn day_number() -> u32 {
-        3
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        match part {
+            Part::One => format!("priorities sum for the mistaken types: {answer}"),
+            Part::Two => format!("priorities sum for the group names: {answer}"),
+        }
     }
 }
 
@@ -107,13 +140,7 @@ mod tests {
         .parse()
         .unwrap();
 
-        assert_eq!(
-            solution.solve(Part::One),
-            "priorities sum for the mistaken types: 157"
-        );
-        assert_eq!(
-            solution.solve(Part::Two),
-            "priorities sum for the group names: 70"
-        );
+        assert_eq!(solution.try_solve(Part::One), Ok(Answer::Number(157)));
+        assert_eq!(solution.try_solve(Part::Two), Ok(Answer::Number(70)));
     }
 }
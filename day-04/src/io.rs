@@ -0,0 +1,50 @@
+//! Reading the assignments line by line.
+
+use std::{error, io::BufRead, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{read, FromReader, TrySolve};
+
+use crate::{Overlaps, Solution};
+
+impl FromStr for Solution {
+    type Err = Box<dyn error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s)?)
+    }
+}
+
+impl FromReader for Overlaps {
+    fn from_reader(reader: impl BufRead) -> common::Result<Self> {
+        let mut overlaps = Self::default();
+        read::lines(reader, |line| overlaps.line(line))?;
+
+        Ok(overlaps)
+    }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.solve_or_error(part)
+    }
+
+    fn day_number() -> u32 {
+        4
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use common::Answer;
+
+    #[test]
+    fn test_read() {
+        let overlaps = Overlaps::from_reader("2-4,6-8\n2-8,3-7\n6-6,4-6\n".as_bytes()).unwrap();
+
+        assert_eq!(overlaps.try_solve(common::Part::One), Ok(Answer::Number(2)));
+        assert_eq!(overlaps.try_solve(common::Part::Two), Ok(Answer::Number(2)));
+    }
+}
@@ -1,70 +1,134 @@
-use std::{error::Error, ops::RangeInclusive, str::FromStr};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use advent_utils::{Part, Solver};
-use color_eyre::eyre::Result;
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{format, string::String, vec::Vec};
+use core::{fmt, ops::RangeInclusive};
+
+use common::{parse::Input, Answer, Part, Result, TrySolve};
 
 #[derive(Debug)]
 pub struct Solution {
     tasks: Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>,
 }
 
-fn parse_range(s: &str) -> Result<RangeInclusive<u32>> {
-    let (left, right) = s.split_once('-').expect("no delimiter in string");
+/// Parses `range`, a slice of the `input`.
+fn parse_range(range: Input<'_>) -> Result<RangeInclusive<u32>> {
+    let (left, right) = range.split_once("-")?;
 
-    Ok(left.parse()?..=right.parse()?)
+    Ok(left.number()?..=right.number()?)
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
+/// `2-4,6-8`
+fn parse_pair(line: Input<'_>) -> Result<(RangeInclusive<u32>, RangeInclusive<u32>)> {
+    let (left, right) = line.split_once(",")?;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let tasks = s
-            .lines()
-            .map(|line| {
-                let (left, right) = line.split_once(',').unwrap();
+    Ok((parse_range(left)?, parse_range(right)?))
+}
+
+fn contains(left: &RangeInclusive<u32>, right: &RangeInclusive<u32>) -> bool {
+    (left.contains(right.start()) && left.contains(right.end()))
+        || (right.contains(left.start()) && right.contains(left.end()))
+}
 
-                (parse_range(left).unwrap(), parse_range(right).unwrap())
-            })
-            .collect();
+fn intersects(left: &RangeInclusive<u32>, right: &RangeInclusive<u32>) -> bool {
+    left.contains(right.start())
+        || left.contains(right.end())
+        || right.contains(left.start())
+        || right.contains(left.end())
+}
+
+fn describe(part: &Part, answer: &Answer) -> String {
+    match part {
+        Part::One => format!("{answer} ranges fully include one another"),
+        Part::Two => format!("{answer} ranges intersect"),
+    }
+}
+
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
+        let tasks = Input::new(s)
+            .lines()
+            .map(parse_pair)
+            .collect::<Result<_>>()?;
 
         Ok(Self { tasks })
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+/// Writes the assignments back in the input format.
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (left, right) in &self.tasks {
+            writeln!(
+                f,
+                "{}-{},{}-{}",
+                left.start(),
+                left.end(),
+                right.start(),
+                right.end()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> Result<Answer> {
+        let n_ranges = match part {
+            Part::One => self
+                .tasks
+                .iter()
+                .filter(|(left, right)| contains(left, right))
+                .count(),
+            Part::Two => self
+                .tasks
+                .iter()
+                .filter(|(left, right)| intersects(left, right))
+                .count(),
+        };
+
+        Answer::try_from(n_ranges)
+    }
+
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
+    }
+}
+
+/// Numbers of pairs for both parts, counted while reading the assignments instead of keeping
+/// them.
+#[derive(Debug, Default)]
+pub struct Overlaps {
+    containing: usize,
+    intersecting: usize,
+}
+
+impl Overlaps {
+    /// Counts the pair of the next line of the assignments.
+    pub fn line(&mut self, line: Input<'_>) -> Result<()> {
+        let (left, right) = parse_pair(line)?;
+        self.containing += usize::from(contains(&left, &right));
+        self.intersecting += usize::from(intersects(&left, &right));
+
+        Ok(())
+    }
+}
+
+impl TrySolve for Overlaps {
+    fn try_solve(&self, part: Part) -> Result<Answer> {
         match part {
-            Part::One => {
-                let n_inclusive = self
-                    .tasks
-                    .iter()
-                    .filter(|(left, right)| {
-                        (left.contains(right.start()) && left.contains(right.end()))
-                            || (right.contains(left.start()) && right.contains(left.end()))
-                    })
-                    .count();
-
-                format!("{n_inclusive} ranges fully include one another")
-            }
-            Part::Two => {
-                let n_overlaps = self
-                    .tasks
-                    .iter()
-                    .filter(|(left, right)| {
-                        left.contains(right.start())
-                            || left.contains(right.end())
-                            || right.contains(left.start())
-                            || right.contains(left.end())
-                    })
-                    .count();
-
-                format!("{n_overlaps} ranges intersect")
-            }
+            Part::One => Answer::try_from(self.containing),
+            Part::Two => Answer::try_from(self.intersecting),
         }
     }
 
-    fn day_number() -> u32 {
-        4
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
     }
 }
 
@@ -85,10 +149,7 @@ mod tests {
         .parse()
         .expect("failed to parse Solution");
 
-        assert_eq!(
-            solution.solve(Part::One),
-            "2 ranges fully include one another"
-        );
-        assert_eq!(solution.solve(Part::Two), "4 ranges intersect");
+        assert_eq!(solution.try_solve(Part::One), Ok(Answer::Number(2)));
+        assert_eq!(solution.try_solve(Part::Two), Ok(Answer::Number(4)));
     }
 }
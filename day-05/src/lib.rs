@@ -1,7 +1,18 @@
-use std::{error::Error, str::FromStr};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use advent_utils::{Part, Solver};
-use color_eyre::{eyre::eyre, Report, Result};
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use common::{parse::Input, trace, Answer, Error, Part, Result, TrySolve};
 use wyz::BidiIterator;
 
 #[derive(Debug)]
@@ -11,28 +22,17 @@ struct Movement {
     n_to_move: usize,
 }
 
-impl FromStr for Movement {
-    type Err = Report;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (n_to_move, rest) = s
-            .strip_prefix("move ")
-            .and_then(|rest| rest.split_once(' '))
-            .ok_or_else(|| eyre!("unknown movement format: {s}"))?;
-
-        let (from, rest) = rest
-            .strip_prefix("from ")
-            .and_then(|rest| rest.split_once(' '))
-            .ok_or_else(|| eyre!("unknown movement format: {s}"))?;
-
-        let to = rest
-            .strip_prefix("to ")
-            .ok_or_else(|| eyre!("unknown movement format: {s}"))?;
+impl Movement {
+    /// `move 1 from 2 to 1`
+    fn parse(line: Input<'_>) -> Result<Self> {
+        let (n_to_move, rest) = line.literal("move ")?.split_once(" ")?;
+        let (from, rest) = rest.literal("from ")?.split_once(" ")?;
+        let to = rest.literal("to ")?;
 
         Ok(Self {
-            from: from.parse()?,
-            to: to.parse()?,
-            n_to_move: n_to_move.parse()?,
+            from: from.number()?,
+            to: to.number()?,
+            n_to_move: n_to_move.number()?,
         })
     }
 }
@@ -44,25 +44,22 @@ pub struct Solution {
     movements: Vec<Movement>,
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (stacks, movements) = s
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
+        let input = Input::new(s);
+        let (stacks, movements) = input
             .split_once("\n\n")
-            .ok_or_else(|| eyre!("cannot split initial data"))?;
+            .map_err(|_| input.error_at_end("no empty line between stacks and movements"))?;
         let mut stack_lines = stacks.lines().rev();
 
-        let n_stacks = stack_lines
+        let numbers_line = stack_lines
             .next()
-            .ok_or_else(|| eyre!("no data in stacks"))?
-            .split_ascii_whitespace()
-            .count();
+            .ok_or_else(|| stacks.error("no data in stacks"))?;
+        let n_stacks = numbers_line.as_str().split_ascii_whitespace().count();
 
         if n_stacks > 9 {
-            return Err(eyre!(
-                "current solution only nows how to deal with no more than 9 stacks :("
-            ))?;
+            return Err(numbers_line
+                .error("current solution only nows how to deal with no more than 9 stacks :("))?;
         }
 
         let mut stacks = Vec::with_capacity(n_stacks);
@@ -73,18 +70,23 @@ impl FromStr for Solution {
 
             let mut idx = 0;
             while !line.is_empty() {
-                let (crate_, rest) = if line.len() > 4 {
-                    line.split_at(CRATE_WIDTH + 1)
-                } else {
-                    (line, "")
+                // by chars, splitting non-ASCII line by bytes could panic
+                let at = match line.as_str().char_indices().nth(CRATE_WIDTH + 1) {
+                    Some((at, _)) => at,
+                    None => line.as_str().len(),
                 };
+                let (crate_, rest) = line.split_at(at);
 
-                if !crate_.trim().is_empty() {
-                    stacks[idx].push(
+                if !crate_.as_str().trim().is_empty() {
+                    let stack = stacks.get_mut(idx).ok_or_else(|| {
+                        crate_.error(format!("crate outside of {n_stacks} stacks"))
+                    })?;
+                    stack.push(
                         crate_
+                            .as_str()
                             .chars()
                             .nth(1)
-                            .ok_or_else(|| eyre!("crate with unknown format: {crate_}"))?,
+                            .ok_or_else(|| crate_.error("crate with unknown format"))?,
                     );
                 }
 
@@ -93,17 +95,28 @@ impl FromStr for Solution {
             }
         }
 
+        let stack_numbers = 1..=n_stacks;
         let movements = movements
             .lines()
-            .map(Movement::from_str)
+            .map(|line| {
+                let movement = Movement::parse(line)?;
+                if !stack_numbers.contains(&movement.from) || !stack_numbers.contains(&movement.to)
+                {
+                    return Err(
+                        line.error(format!("movement of unknown stacks, there are {n_stacks}"))
+                    );
+                }
+
+                Ok(movement)
+            })
             .collect::<Result<Vec<_>>>()?;
 
         Ok(Self { stacks, movements })
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> Result<Answer> {
         let mut stacks = self.stacks.clone();
 
         for &Movement {
@@ -113,6 +126,12 @@ impl Solver for Solution {
         } in &self.movements
         {
             let from_len = stacks[from - 1].len();
+            if n_to_move > from_len {
+                return Err(Error::invalid_state(format!(
+                    "moving {n_to_move} crates from stack {from} with {from_len}"
+                )));
+            }
+
             let mut moved = {
                 stacks[from - 1]
                     .drain(from_len - n_to_move..)
@@ -122,6 +141,21 @@ impl Solver for Solution {
             };
 
             stacks[to - 1].append(&mut moved);
+
+            trace::event("move", || {
+                let stacks = stacks
+                    .iter()
+                    .enumerate()
+                    .map(|(i, stack)| format!("{}: {}\n", i + 1, String::from_iter(stack)))
+                    .collect();
+
+                vec![
+                    ("n", n_to_move.to_string()),
+                    ("from", from.to_string()),
+                    ("to", to.to_string()),
+                    ("stacks", stacks),
+                ]
+            });
         }
 
         let final_: String = stacks
@@ -129,11 +163,11 @@ impl Solver for Solution {
             .map(|stack| stack.last().unwrap_or(&' '))
             .collect();
 
-        format!("top crates are: `{final_}`")
+        Ok(Answer::Text(final_))
     }
 
-    fn day_number() -> u32 {
-        5
+    fn describe(&self, _part: &Part, answer: &Answer) -> String {
+        format!("top crates are: `{answer}`")
     }
 }
 
@@ -156,7 +190,14 @@ move 1 from 1 to 2"#
             .parse()
             .expect("failed to parse solution");
 
-        assert_eq!(solution.solve(Part::One), "top crates are: `CMZ`");
-        assert_eq!(solution.solve(Part::Two), "top crates are: `MCD`");
+        assert_eq!(
+            solution.try_solve(Part::One),
+            Ok(Answer::Text("CMZ".to_owned()))
+        );
+        assert_eq!(
+            solution.try_solve(Part::Two),
+            Ok(Answer::Text("MCD".to_owned()))
+        );
+        assert_eq!(solution.solve_or_error(Part::Two), "top crates are: `MCD`");
     }
 }
@@ -0,0 +1,59 @@
+//! Reading the datastream byte by byte.
+
+use std::{error, io::BufRead, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{read, FromReader, TrySolve};
+
+use crate::{Datastream, Solution};
+
+impl FromStr for Solution {
+    type Err = Box<dyn error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s)?)
+    }
+}
+
+impl FromReader for Datastream {
+    fn from_reader(reader: impl BufRead) -> common::Result<Self> {
+        let mut datastream = Self::default();
+        for byte in read::bytes(reader) {
+            datastream.push(byte?);
+        }
+
+        Ok(datastream)
+    }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.solve_or_error(part)
+    }
+
+    fn day_number() -> u32 {
+        6
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use common::Answer;
+
+    #[test]
+    fn test_read() {
+        let datastream =
+            Datastream::from_reader("bvwbjplbgvbhsrlpgdmjqwftvncz\n".as_bytes()).unwrap();
+
+        assert_eq!(
+            datastream.try_solve(common::Part::One),
+            Ok(Answer::Number(5))
+        );
+        assert_eq!(
+            datastream.try_solve(common::Part::Two),
+            Ok(Answer::Number(23))
+        );
+    }
+}
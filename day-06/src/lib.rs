@@ -1,52 +1,140 @@
-use std::{error::Error, str::FromStr};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use advent_utils::{Part, Solver};
-use color_eyre::Result;
-use fnv::FnvHashSet;
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{borrow::ToOwned, collections::VecDeque, format, string::String};
+
+use common::{Answer, Error, Part, TrySolve};
 
 #[derive(Debug)]
 pub struct Solution {
     stream: String,
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
         Ok(Self {
             stream: s.to_owned(),
         })
     }
 }
 
-fn find_packet_start(stream: &str, packet_size: usize) -> Option<usize> {
-    let bytes: Vec<_> = stream.bytes().collect();
+/// End of the first window of `size` different bytes of `stream`.
+fn find_marker(stream: &str, size: usize) -> Option<usize> {
+    let mut marker = Marker::new(size);
+    for byte in stream.bytes() {
+        marker.push(byte);
+        if marker.end.is_some() {
+            break;
+        }
+    }
 
-    bytes
-        .windows(packet_size)
-        .enumerate()
-        .find_map(|(i, window)| {
-            let set = FnvHashSet::from_iter(window);
+    marker.end
+}
 
-            (set.len() == packet_size).then_some(i + packet_size)
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
+        let size = match part {
+            Part::One => 4,
+            Part::Two => 14,
+        };
+
+        answer(find_marker(&self.stream, size))
+    }
+
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
+    }
+}
+
+fn answer(end: Option<usize>) -> common::Result<Answer> {
+    Answer::try_from(end.ok_or_else(|| Error::unsolvable("couldn't find packet start"))?)
+}
+
+fn describe(_part: &Part, answer: &Answer) -> String {
+    format!("packet starts at {answer}")
+}
+
+/// Both markers, looked for while reading the stream instead of keeping it.
+#[derive(Debug)]
+pub struct Datastream {
+    packet: Marker,
+    message: Marker,
+}
+
+impl Default for Datastream {
+    fn default() -> Self {
+        Self {
+            packet: Marker::new(4),
+            message: Marker::new(14),
+        }
+    }
+}
+
+impl Datastream {
+    /// Looks for the markers in the next byte of the stream.
+    pub fn push(&mut self, byte: u8) {
+        self.packet.push(byte);
+        self.message.push(byte);
+    }
+}
+
+impl TrySolve for Datastream {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
+        answer(match part {
+            Part::One => self.packet.end,
+            Part::Two => self.message.end,
         })
+    }
+
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
+    }
 }
 
-impl Solver for Solution {
-    fn day_number() -> u32 {
-        6
+/// Finds the end of the first window of `size` different bytes in a stream fed byte by byte.
+#[derive(Debug)]
+struct Marker {
+    size: usize,
+    window: VecDeque<u8>,
+    n_bytes: usize,
+    end: Option<usize>,
+}
+
+impl Marker {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            window: VecDeque::with_capacity(size + 1),
+            n_bytes: 0,
+            end: None,
+        }
     }
 
-    fn solve(&self, part: Part) -> String {
-        let packet_size = match part {
-            Part::One => 4,
-            Part::Two => 14,
-        };
+    fn push(&mut self, byte: u8) {
+        if self.end.is_some() {
+            return;
+        }
 
-        let start =
-            find_packet_start(&self.stream, packet_size).expect("couldn't find packet start");
+        self.n_bytes += 1;
+        self.window.push_back(byte);
+        if self.window.len() > self.size {
+            self.window.pop_front();
+        }
 
-        format!("packet starts at {start}")
+        // windows are short, comparing every pair is cheaper than hashing them
+        let distinct = (1..self.window.len()).all(|i| {
+            !self
+                .window
+                .range(i..)
+                .any(|&other| other == self.window[i - 1])
+        });
+        if self.window.len() == self.size && distinct {
+            self.end = Some(self.n_bytes);
+        }
     }
 }
 
@@ -56,9 +144,17 @@ mod tests {
 
     #[test]
     fn test_examples() {
+        assert_eq!(find_marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4), Some(7));
+        assert_eq!(find_marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14), Some(19));
+    }
+
+    #[test]
+    fn test_no_packet_start() {
+        let solution: Solution = "abcabcabcabc".parse().unwrap();
+
         assert_eq!(
-            find_packet_start("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4),
-            Some(7)
+            solution.try_solve(Part::One),
+            Err(Error::unsolvable("couldn't find packet start"))
         );
     }
 }
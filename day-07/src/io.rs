@@ -0,0 +1,24 @@
+use std::{error, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::TrySolve;
+
+use crate::Solution;
+
+impl FromStr for Solution {
+    type Err = Box<dyn error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s)?)
+    }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.solve_or_error(part)
+    }
+
+    fn day_number() -> u32 {
+        7
+    }
+}
@@ -1,94 +1,143 @@
-use std::{
-    error::Error,
-    path::{Path, PathBuf},
-    str::FromStr,
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
 };
 
-use advent_utils::{Part, Solver};
-use color_eyre::{eyre::eyre, Report};
+use common::{parse::Input, trace, Answer, Error, Part, Result, TrySolve};
 
-type Map<K, V> = fnv::FnvHashMap<K, V>;
+type Map<K, V> = BTreeMap<K, V>;
+
+/// Names of the directories leading to an entry, the root is empty.
+pub type Path = Vec<String>;
 
 #[derive(Debug)]
 pub struct Solution {
-    entries: Map<PathBuf, FsEntry>,
+    entries: Map<Path, FsEntry>,
 }
 
+/// Changes `path` to `dir_name`, which may be absolute or several levels deep like `a/e`.
+fn change_dir(path: &mut Path, dir_name: &str) {
+    if dir_name.starts_with('/') {
+        path.clear();
+    }
+    path.extend(
+        dir_name
+            .split('/')
+            .filter(|name| !name.is_empty())
+            .map(str::to_owned),
+    );
+}
+
+/// `/a/e`, or `/` for the root.
+fn display(path: &Path) -> String {
+    match path.is_empty() {
+        true => "/".to_owned(),
+        false => path.iter().map(|name| format!("/{name}")).collect(),
+    }
+}
+
+/// Parses `cmd_log`: the command line and the output of the command, if any.
 fn parse_command(
-    cmd_log: &str,
-    current_path: &mut PathBuf,
-    fs_entries: &mut Map<PathBuf, FsEntry>,
-) -> Result<(), Report> {
-    let (cmd_name, rest) = cmd_log
-        .split_once([' ', '\n'])
-        .ok_or_else(|| eyre!("invalid cmd format: {cmd_log}"))?;
-
-    match cmd_name {
-        "cd" => {
-            if rest == ".." {
+    cmd_log: Input<'_>,
+    current_path: &mut Path,
+    fs_entries: &mut Map<Path, FsEntry>,
+) -> Result<()> {
+    let mut lines = cmd_log.lines();
+    let command = lines.next().ok_or_else(|| cmd_log.error("no command"))?;
+
+    match command.split_once(" ") {
+        Ok((cmd_name, dir_name)) if cmd_name.as_str() == "cd" => {
+            lines.end()?;
+
+            if dir_name.as_str() == ".." {
                 current_path.pop();
             } else {
-                let dir_name = rest.to_owned();
-                current_path.push(dir_name);
+                change_dir(current_path, dir_name.as_str());
                 fs_entries.insert(current_path.clone(), FsEntry::Dir(vec![]));
             }
+            trace::event("cd", || vec![("path", display(current_path))]);
+
             Ok(())
         }
-        "ls" => {
+        _ if command.as_str() == "ls" => {
             let current_entry = fs_entries
                 .get_mut(current_path)
-                .ok_or_else(|| eyre!("listing unknown dir"))?;
+                .ok_or_else(|| command.error("listing unknown dir"))?;
 
-            let entries = rest
-                .lines()
+            let entries = lines
                 .map(|line| {
                     let mut path = current_path.clone();
-
-                    match line.split_once(' ') {
-                        Some(("dir", name)) => {
-                            let entry = FsEntry::Dir(vec![]);
-                            path.push(name);
-
-                            current_entry.push(path.clone())?;
-                            Ok((path, entry))
-                        }
-                        Some((size, name)) => {
-                            let entry = FsEntry::File(size.parse()?);
-                            path.push(name);
-
-                            current_entry.push(path.clone())?;
-                            Ok((path, entry))
-                        }
-                        None => Err(eyre!("invalid format: {line}")),
-                    }
+                    let (kind, name) = line.split_once(" ")?;
+                    let entry = match kind.as_str() {
+                        "dir" => FsEntry::Dir(vec![]),
+                        _ => FsEntry::File(kind.number()?),
+                    };
+                    change_dir(&mut path, name.as_str());
+
+                    current_entry.push(path.clone())?;
+                    Ok((path, entry))
                 })
-                .collect::<Result<Vec<_>, _>>()?;
+                .collect::<common::Result<Vec<_>>>()?;
+            trace::event("ls", || {
+                let n_dirs = entries
+                    .iter()
+                    .filter(|(_, entry)| matches!(entry, FsEntry::Dir(_)))
+                    .count();
+
+                vec![
+                    ("path", display(current_path)),
+                    ("dirs", n_dirs.to_string()),
+                    ("files", (entries.len() - n_dirs).to_string()),
+                ]
+            });
             fs_entries.extend(entries);
 
             Ok(())
         }
-        _ => Err(eyre!("unknown cmd: {cmd_name}")),
+        _ => Err(command.error("unknown cmd")),
     }
 }
 
 #[derive(Debug, Clone)]
 pub enum FsEntry {
-    Dir(Vec<PathBuf>),
+    Dir(Vec<Path>),
     File(usize),
 }
 
 impl FsEntry {
-    fn size(&self, entries_map: &Map<PathBuf, FsEntry>) -> Result<usize, Report> {
+    fn size(&self, entries_map: &Map<Path, FsEntry>) -> Result<usize> {
         match self {
             Self::File(size) => Ok(*size),
             Self::Dir(entries) => {
-                let mut size_sum = 0;
+                let mut size_sum: usize = 0;
 
                 for entry_path in entries {
-                    size_sum += entries_map
+                    let size = entries_map
                         .get(entry_path)
-                        .ok_or_else(|| eyre!("tying to check unknown entry {entry_path:?}"))?
+                        .ok_or_else(|| {
+                            Error::invalid_state(format!(
+                                "trying to check unknown entry {:?}",
+                                display(entry_path)
+                            ))
+                        })?
                         .size(entries_map)?;
+                    size_sum = size_sum.checked_add(size).ok_or_else(|| {
+                        Error::invalid_state(format!(
+                            "total size overflows at entry {:?}",
+                            display(entry_path)
+                        ))
+                    })?;
                 }
 
                 Ok(size_sum)
@@ -96,9 +145,9 @@ impl FsEntry {
         }
     }
 
-    fn push(&mut self, entry: PathBuf) -> Result<(), Report> {
+    fn push(&mut self, entry: Path) -> Result<()> {
         match self {
-            Self::File(_) => Err(eyre!("trying to push to the file")),
+            Self::File(_) => Err(Error::invalid_state("trying to push to the file")),
             Self::Dir(entries) => {
                 entries.push(entry);
 
@@ -108,38 +157,44 @@ impl FsEntry {
     }
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
         let mut entries = Default::default();
-        let mut current_path = PathBuf::new();
+        let mut current_path = Path::new();
 
-        let cmd_logs = s.split("$ ").skip_while(|&s| s.is_empty());
+        let cmd_logs = Input::new(s)
+            .records("$ ")
+            .skip_while(|cmd_log| cmd_log.is_empty());
         for cmd_log in cmd_logs {
-            parse_command(cmd_log.trim(), &mut current_path, &mut entries)?;
+            parse_command(cmd_log, &mut current_path, &mut entries)?;
         }
 
         Ok(Self { entries })
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl Solution {
+    /// Sizes of all directories.
+    fn dir_sizes(&self) -> Result<Vec<usize>> {
+        self.entries
+            .values()
+            .filter(|entry| matches!(entry, FsEntry::Dir(_)))
+            .map(|entry| entry.size(&self.entries))
+            .collect()
+    }
+}
+
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> Result<Answer> {
         match part {
             Part::One => {
                 let sizes_sum: usize = self
-                    .entries
-                    .values()
-                    .filter(|entry| matches!(entry, FsEntry::Dir(_)))
-                    .filter_map(|entry| {
-                        let size = entry.size(&self.entries).unwrap();
-
-                        (size <= 100000).then_some(size)
-                    })
+                    .dir_sizes()?
+                    .into_iter()
+                    .filter(|&size| size <= 100000)
                     .sum();
 
-                format!("total file sizes sum is {sizes_sum}")
+                Answer::try_from(sizes_sum)
             }
             Part::Two => {
                 const DISK_SIZE: usize = 70_000_000;
@@ -147,35 +202,36 @@ impl Solver for Solution {
 
                 let root_size = self
                     .entries
-                    .get(Path::new("/"))
-                    .expect("no root parsed")
-                    .size(&self.entries)
-                    .expect("can't get root size");
+                    .get(&Path::new())
+                    .ok_or_else(|| Error::invalid_state("no root parsed"))?
+                    .size(&self.entries)?;
 
-                let free_size = DISK_SIZE - root_size;
+                let free_size = DISK_SIZE.checked_sub(root_size).ok_or_else(|| {
+                    Error::invalid_state(format!("files take {root_size}, more than the disk"))
+                })?;
                 let need_to_free = match MUST_BE_FREE_SIZE.checked_sub(free_size) {
-                    None => return "there is already enough free space :)".to_owned(),
+                    // nothing has to be deleted
+                    None => return Ok(Answer::Number(0)),
                     Some(need_to_free) => need_to_free,
                 };
 
                 let min_dir_to_del_size = self
-                    .entries
-                    .values()
-                    .filter(|entry| matches!(entry, FsEntry::Dir(_)))
-                    .filter_map(|entry| {
-                        let size = entry.size(&self.entries).unwrap();
-
-                        (size >= need_to_free).then_some(size)
-                    })
+                    .dir_sizes()?
+                    .into_iter()
+                    .filter(|&size| size >= need_to_free)
                     .min()
-                    .expect("no suitable dir to delete found :(");
+                    .ok_or_else(|| Error::unsolvable("no suitable dir to delete found :("))?;
 
-                format!("min dir to delete has size {min_dir_to_del_size}")
+                Answer::try_from(min_dir_to_del_size)
             }
         }
     }
 
-    fn day_number() -> u32 {
-        7
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        match (part, answer) {
+            (Part::One, _) => format!("total file sizes sum is {answer}"),
+            (Part::Two, Answer::Number(0)) => "there is already enough free space :)".to_owned(),
+            (Part::Two, _) => format!("min dir to delete has size {answer}"),
+        }
     }
 }
@@ -1,117 +1,99 @@
-use std::{error::Error, str::FromStr};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use advent_utils::{Part, Solver};
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{format, string::String};
+use core::fmt;
+
+use common::{Answer, Part, TrySolve};
+use grid::{Direction, Grid, Point};
 
 #[derive(Debug)]
 pub struct Solution {
-    map: Vec<Vec<u8>>,
+    map: Grid<u8>,
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
+        let map = Grid::parse(s, |c| match c {
+            '0'..='9' => Ok(c as u8 - b'0'),
+            _ => Err("unknown tree height"),
+        })?;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            map: s
-                .lines()
-                .map(|line| line.bytes().map(|b| b - b'0').collect())
-                .collect(),
-        })
+        Ok(Self { map })
     }
 }
 
-fn check_if_visible_naive(map: &[Vec<u8>], x: usize, y: usize) -> bool {
-    let len = map.len();
-
-    if x == 0 || x == len - 1 || y == 0 || y == len - 1 {
-        return true;
+/// Writes the map back in the input format.
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.map.render(|&height| char::from(b'0' + height))
+        )
     }
+}
 
-    let current = map[y][x];
-
-    (0..y).map(|y| map[y][x]).all(|height| height < current) // from top
-    || (y + 1..len).map(|y| map[y][x]).all(|height| height < current) // from bottom
-    || (0..x).map(|x| map[y][x]).all(|height| height < current) // from left
-    || (x + 1..len).map(|x| map[y][x]).all(|height| height < current) // from right
+/// Heights of the trees from the one at `point` to the edge in `direction`.
+fn heights(map: &Grid<u8>, point: Point, direction: Direction) -> impl Iterator<Item = u8> + '_ {
+    map.ray(point, direction).map(|(_, &height)| height)
 }
 
-fn compute_score_naive(map: &[Vec<u8>], x: usize, y: usize) -> u32 {
-    let len = map.len();
+fn is_visible(map: &Grid<u8>, point: Point) -> bool {
+    let current = map[point];
 
-    if x == 0 || x == len - 1 || y == 0 || y == len - 1 {
-        return 0;
-    }
+    Direction::ALL
+        .into_iter()
+        .any(|direction| heights(map, point, direction).all(|height| height < current))
+}
 
-    macro_rules! stop_if_zero {
-        ($check: expr, $do: expr) => {
-            if $check == 0 {
-                return 0;
-            } else {
-                $do
+fn scenic_score(map: &Grid<u8>, point: Point) -> u32 {
+    let current = map[point];
+
+    Direction::ALL
+        .into_iter()
+        .map(|direction| {
+            let mut distance = 0;
+            for height in heights(map, point, direction) {
+                distance += 1;
+                if height >= current {
+                    break;
+                }
             }
-        };
-    }
 
-    let current = map[y][x];
-
-    let nearest_top = (y - (0..y).rev().find(|&y| map[y][x] >= current).unwrap_or(0)) as u32;
-    let nearest_bottom = stop_if_zero!(
-        nearest_top,
-        (y + 1..len)
-            .find(|&y| map[y][x] >= current)
-            .unwrap_or(len - 1)
-            - y
-    ) as u32;
-    let nearest_left = stop_if_zero!(
-        nearest_bottom,
-        x - (0..x).rev().find(|&x| map[y][x] >= current).unwrap_or(0)
-    ) as u32;
-    let nearest_right = stop_if_zero!(
-        nearest_left,
-        (x + 1..len)
-            .find(|&x| map[y][x] >= current)
-            .unwrap_or(len - 1)
-            - x
-    ) as u32;
-
-    nearest_top * nearest_bottom * nearest_left * nearest_right
+            distance
+        })
+        .product()
 }
 
-fn count_visible(map: &[Vec<u8>]) -> usize {
-    (0..map.len())
-        .map(|y| {
-            (0..map[y].len())
-                .filter(|&x| check_if_visible_naive(map, x, y))
-                .count()
-        })
-        .sum()
+fn count_visible(map: &Grid<u8>) -> usize {
+    map.points().filter(|&point| is_visible(map, point)).count()
 }
 
-fn max_scenic_score(map: &[Vec<u8>]) -> u32 {
-    (0..map.len())
-        .map(|y| {
-            (0..map[y].len())
-                .map(|x| compute_score_naive(map, x, y))
-                .max()
-                .unwrap_or(0)
-        })
+fn max_scenic_score(map: &Grid<u8>) -> u32 {
+    map.points()
+        .map(|point| scenic_score(map, point))
         .max()
         .unwrap_or(0)
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
         match part {
-            Part::One => format!(
-                "{} trees are visible from the outside",
-                count_visible(&self.map)
-            ),
-            Part::Two => format!("max scenic score is: {}", max_scenic_score(&self.map)),
+            Part::One => Answer::try_from(count_visible(&self.map)),
+            Part::Two => Ok(max_scenic_score(&self.map).into()),
         }
     }
 
-    fn day_number() -> u32 {
-        8
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        match part {
+            Part::One => format!("{answer} trees are visible from the outside"),
+            Part::Two => format!("max scenic score is: {answer}"),
+        }
     }
 }
 
@@ -136,6 +118,21 @@ mod tests {
         assert_eq!(max_scenic_score(&solution.map), 8);
     }
 
+    #[test]
+    fn test_not_square() {
+        let solution: Solution = indoc::indoc! {
+            "30373
+            25512
+            65332"
+        }
+        .parse()
+        .unwrap();
+
+        assert_eq!(count_visible(&solution.map), 14);
+
+        assert_eq!(max_scenic_score(&solution.map), 2);
+    }
+
     #[test]
     fn test_my_examples() {
         let solution: Solution = indoc::indoc! {
@@ -0,0 +1,39 @@
+//! Reading the moves line by line.
+
+use std::{error, io::BufRead, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{read, FromReader, TrySolve};
+
+use crate::{Solution, Tails};
+
+impl FromStr for Solution {
+    type Err = Box<dyn error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s)?)
+    }
+}
+
+impl FromReader for Tails {
+    fn from_reader(reader: impl BufRead) -> common::Result<Self> {
+        let mut tails = Self::default();
+        read::lines(reader, |line| tails.line(line))?;
+
+        Ok(tails)
+    }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.solve_or_error(part)
+    }
+
+    fn day_number() -> u32 {
+        9
+    }
+
+    fn implemented_parts() -> Vec<Part> {
+        vec![Part::One, Part::Two]
+    }
+}
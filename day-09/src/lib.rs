@@ -1,98 +1,180 @@
-use std::{
-    collections::HashSet,
-    error::Error,
-    fmt,
-    iter::{empty, repeat},
-    str::FromStr,
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
 };
+use core::fmt;
 
-use advent_utils::{Part, Solver};
-use color_eyre::{eyre::eyre, Report};
+use common::{parse::Input, trace, Answer, Part, TrySolve};
+use grid::{Direction, Grid, Point};
 use lending_iterator::{windows_mut, LendingIterator};
 
-type Set<T> = HashSet<T>;
+#[cfg(feature = "std")]
+type Set<T> = std::collections::HashSet<T>;
+// there are no hash sets in `alloc`
+#[cfg(not(feature = "std"))]
+type Set<T> = alloc::collections::BTreeSet<T>;
 
 #[derive(Debug)]
 pub struct Solution {
     moves: Vec<Move>,
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let moves = s.lines().map(str::parse).collect::<Result<_, _>>()?;
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
+        let moves = Input::new(s)
+            .lines()
+            .map(Move::parse)
+            .collect::<common::Result<_>>()?;
 
         Ok(Self { moves })
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
-        match part {
-            Part::One => {
-                let mut rope = Rope::new(2);
-                let mut visited = Set::new();
-                visited.insert((0, 0));
+/// Writes the moves back in the input format.
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for move_ in &self.moves {
+            writeln!(f, "{move_}")?;
+        }
 
-                self.moves
-                    .iter()
-                    .for_each(|&move_| visited.extend(rope.make_move(move_)));
+        Ok(())
+    }
+}
 
-                format!("tail visited {} unique positions", visited.len())
-            }
-            Part::Two => {
-                let mut rope = Rope::new(10);
-                let mut visited = Set::new();
-                visited.insert((0, 0));
-
-                self.moves
-                    .iter()
-                    .for_each(|&move_| visited.extend(rope.make_move(move_)));
-
-                format!(
-                    "tail of 10-segmented rope visited {} unique positions",
-                    visited.len()
-                )
-            }
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
+        let mut tail = Tail::new(part);
+        self.moves.iter().for_each(|&move_| tail.follow(move_));
+
+        tail.answer()
+    }
+
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
+    }
+}
+
+fn describe(part: &Part, answer: &Answer) -> String {
+    match part {
+        Part::One => format!("tail visited {answer} unique positions"),
+        Part::Two => format!("tail of 10-segmented rope visited {answer} unique positions"),
+    }
+}
+
+/// Positions visited by the tail of the rope of the part.
+#[derive(Debug)]
+struct Tail {
+    rope: Rope,
+    visited: Set<Point>,
+}
+
+impl Tail {
+    fn new(part: Part) -> Self {
+        let rope = match part {
+            Part::One => Rope::new(2),
+            Part::Two => Rope::new(10),
+        };
+
+        Self {
+            rope,
+            visited: Set::from([Point::ORIGIN]),
         }
     }
 
-    fn day_number() -> u32 {
-        9
+    fn follow(&mut self, move_: Move) {
+        self.rope.make_move(move_, &mut self.visited);
     }
 
-    fn implemented_parts() -> Vec<Part> {
-        vec![Part::One]
+    fn answer(&self) -> common::Result<Answer> {
+        Answer::try_from(self.visited.len())
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum Move {
-    Left(i64),
-    Right(i64),
-    Up(i64),
-    Down(i64),
+/// Tails of both parts, which follow the moves while they are read instead of keeping them.
+///
+/// Memory grows with the number of visited positions only.
+#[derive(Debug)]
+pub struct Tails {
+    short: Tail,
+    long: Tail,
+}
+
+impl Default for Tails {
+    fn default() -> Self {
+        Self {
+            short: Tail::new(Part::One),
+            long: Tail::new(Part::Two),
+        }
+    }
 }
 
-impl FromStr for Move {
-    type Err = Report;
+impl Tails {
+    /// Follows the move of the next line.
+    pub fn line(&mut self, line: Input<'_>) -> common::Result<()> {
+        let move_ = Move::parse(line)?;
+        self.short.follow(move_);
+        self.long.follow(move_);
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some((dir, n)) = s.split_once(' ') {
-            let nsteps = n.parse()?;
+        Ok(())
+    }
+}
 
-            match dir {
-                "L" => Ok(Self::Left(nsteps)),
-                "R" => Ok(Self::Right(nsteps)),
-                "D" => Ok(Self::Down(nsteps)),
-                "U" => Ok(Self::Up(nsteps)),
-                other => Err(eyre!("unknown move: {other}")),
-            }
-        } else {
-            Err(eyre!("unknown move format: {s}"))
+impl TrySolve for Tails {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
+        match part {
+            Part::One => self.short.answer(),
+            Part::Two => self.long.answer(),
         }
     }
+
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    direction: Direction,
+    n_steps: i64,
+}
+
+impl Move {
+    /// `R 4`
+    fn parse(line: Input<'_>) -> common::Result<Self> {
+        let (dir, n) = line.split_once(" ")?;
+        let n_steps = n.number()?;
+        let direction = match dir.as_str() {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            "D" => Direction::Down,
+            "U" => Direction::Up,
+            _ => return Err(dir.error("unknown move")),
+        };
+
+        Ok(Self { direction, n_steps })
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dir = match self.direction {
+            Direction::Left => "L",
+            Direction::Right => "R",
+            Direction::Up => "U",
+            Direction::Down => "D",
+        };
+
+        write!(f, "{dir} {}", self.n_steps)
+    }
 }
 
 #[derive(Debug)]
@@ -107,31 +189,25 @@ impl fmt::Display for Rope {
         let min_y = self.segments.iter().map(|p| p.y).min().unwrap_or_default();
         let max_y = self.segments.iter().map(|p| p.y).max().unwrap_or_default();
 
-        let mut map: Vec<_> = (min_y..=max_y)
-            .map(|_| vec![b'.'; (max_x - min_x + 1) as usize])
-            .collect();
+        let min = Point::new(min_x, min_y);
+        let mut map = Grid::new(
+            (max_x - min_x + 1) as usize,
+            (max_y - min_y + 1) as usize,
+            '.',
+        );
 
-        for (i, segment) in self.segments.iter().enumerate().rev() {
-            map[(segment.y - min_y) as usize][(segment.x - min_x) as usize] =
-                if i == 0 { b'H' } else { b'0' + i as u8 }
+        for (i, &segment) in self.segments.iter().enumerate().rev() {
+            map[segment - min] = if i == 0 {
+                'H'
+            } else {
+                char::from(b'0' + i as u8)
+            };
         }
 
-        let result = map
-            .into_iter()
-            .reduce(|line_a, line_b| [line_a, vec![b'\n'], line_b].concat())
-            .and_then(|map| String::from_utf8(map).ok())
-            .unwrap_or_default();
-
-        write!(f, "{result}")
+        write!(f, "{}", map.render(|&c| c))
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-struct Point {
-    x: i64,
-    y: i64,
-}
-
 impl Rope {
     pub fn new(n_segments: usize) -> Self {
         Self {
@@ -139,93 +215,53 @@ impl Rope {
         }
     }
 
-    pub fn make_move(&mut self, move_: Move) -> impl Iterator<Item = (i64, i64)> {
-        // println!("Making move {move_:?}, self state: \n{self}");
-
-        match move_ {
-            Move::Left(nsteps) => self.segments[0].x -= nsteps,
-            Move::Right(nsteps) => self.segments[0].x += nsteps,
-            Move::Up(nsteps) => self.segments[0].y -= nsteps,
-            Move::Down(nsteps) => self.segments[0].y += nsteps,
+    /// Moves the head step by step, adding the positions the tail moves to into `visited`.
+    pub fn make_move(&mut self, move_: Move, visited: &mut Set<Point>) {
+        for _ in 0..move_.n_steps {
+            self.segments[0] = self.segments[0].step(move_.direction);
+            if self.pull_tail() {
+                visited.insert(self.segments[self.segments.len() - 1]);
+            }
         }
 
-        self.pull_tail()
+        trace::event("move", || {
+            vec![
+                ("move", move_.to_string()),
+                ("segments", self.segments.len().to_string()),
+                ("rope", self.to_string()),
+            ]
+        });
     }
 
-    fn pull_tail(&mut self) -> Box<dyn Iterator<Item = (i64, i64)>> {
-        let mut iter: Box<dyn Iterator<Item = (i64, i64)>> = Box::new(empty());
+    /// Pulls every segment after the one in front of it, returns whether the tail moved.
+    fn pull_tail(&mut self) -> bool {
+        let mut moved = false;
         let mut windows = windows_mut::<_, 2>(&mut self.segments);
-
         while let Some([head, tail]) = windows.next() {
-            iter = Box::new(Rope::pull_segment(head, tail));
+            moved = Rope::pull_segment(head, tail);
         }
 
-        iter
+        moved
     }
 
-    fn pull_segment(head: &Point, tail: &mut Point) -> Box<dyn Iterator<Item = (i64, i64)>> {
-        let head_x = head.x;
-        let head_y = head.y;
-        let tail_x = tail.x;
-        let tail_y = tail.y;
-
-        match (head_x.abs_diff(tail_x), head_y.abs_diff(tail_y)) {
-            (0, 0) | (1, 0) | (0, 1) | (1, 1) => Box::new(empty()),
-            (dx, dy) => {
-                let x_iter: Box<dyn Iterator<Item = _>> = if head_x > tail_x {
-                    if dx > dy {
-                        tail.x = head_x - 1;
-
-                        Box::new(tail_x + 1..=tail.x)
-                    } else {
-                        tail.x = head_x;
-
-                        Box::new((tail_x + 1..=tail.x).chain(repeat(tail.x)))
-                    }
-                } else if dx > dy {
-                    tail.x = head_x + 1;
-
-                    Box::new((tail.x..=tail_x - 1).rev())
-                } else {
-                    tail.x = head_x;
-
-                    Box::new((tail.x..=tail_x - 1).rev().chain(repeat(tail.x)))
-                };
-
-                let y_iter: Box<dyn Iterator<Item = _>> = if head_y > tail_y {
-                    if dx <= dy {
-                        tail.y = head_y - 1;
-
-                        Box::new(tail_y + 1..=tail.y)
-                    } else {
-                        tail.y = head_y;
-
-                        Box::new((tail_y + 1..=tail.y).chain(repeat(tail.y)))
-                    }
-                } else if dx <= dy {
-                    tail.y = head_y + 1;
-
-                    Box::new((tail.y..=tail_y - 1).rev())
-                } else {
-                    tail.y = head_y;
+    /// Moves `tail` a step towards `head` unless they touch, diagonally if they aren't in line.
+    fn pull_segment(head: &Point, tail: &mut Point) -> bool {
+        if head.touches(*tail) {
+            return false;
+        }
 
-                    Box::new((tail.y..=tail_y - 1).rev().chain(repeat(tail.y)))
-                };
+        *tail = *tail + Point::new((head.x - tail.x).signum(), (head.y - tail.y).signum());
 
-                Box::new(x_iter.zip(y_iter))
-            }
-        }
+        true
     }
 }
 
-// impl fmt::Display for Rope {
-
-// }
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use common::FromReader;
+
     #[test]
     fn test_example() {
         let solution: Solution = indoc::indoc! {
@@ -241,18 +277,28 @@ mod tests {
         .parse()
         .unwrap();
 
-        assert_eq!(
-            solution.solve(Part::One),
-            "tail visited 13 unique positions"
-        );
-        assert_eq!(
-            solution.solve(Part::Two),
-            "tail of 10-segmented rope visited 1 unique positions"
-        );
+        assert_eq!(solution.try_solve(Part::One), Ok(Answer::Number(13)));
+        assert_eq!(solution.try_solve(Part::Two), Ok(Answer::Number(1)));
+
+        let tails = Tails::from_reader(solution.to_string().as_bytes()).unwrap();
+        assert_eq!(tails.try_solve(Part::One), Ok(Answer::Number(13)));
+        assert_eq!(tails.try_solve(Part::Two), Ok(Answer::Number(1)));
+    }
+
+    #[test]
+    fn test_trace() {
+        let solution: Solution = "R 4\nU 4\nL 3\n".parse().unwrap();
+        let (answer, events) = common::trace::capture(|| solution.try_solve(Part::One));
+
+        assert_eq!(answer, Ok(Answer::Number(9)));
+        let ropes: Vec<_> = events
+            .iter()
+            .filter_map(|event| event.field("rope"))
+            .collect();
+        assert_eq!(ropes, ["1H\n", "H\n1\n", "H1\n"]);
     }
 
     #[test]
-    #[ignore = "not yet debugged"]
     fn test_example_two() {
         let solution: Solution = indoc::indoc! {
             "R 5
@@ -267,14 +313,10 @@ mod tests {
         .parse()
         .unwrap();
 
-        assert_eq!(
-            solution.solve(Part::Two),
-            "tail of 10-segmented rope visited 36 unique positions"
-        );
+        assert_eq!(solution.try_solve(Part::Two), Ok(Answer::Number(36)));
     }
 
     #[test]
-    #[ignore = "not yet debugged"]
     fn test_my_examples() {
         let solution: Solution = indoc::indoc! {
             "R 5"
@@ -283,11 +325,11 @@ mod tests {
         .unwrap();
 
         let mut rope = Rope::new(10);
-        let mut visited = vec![(0, 0)];
+        let mut visited = Set::from([Point::ORIGIN]);
         solution
             .moves
             .iter()
-            .for_each(|&move_| visited.extend(rope.make_move(move_)));
+            .for_each(|&move_| rope.make_move(move_, &mut visited));
 
         assert_eq!(visited.len(), 1, "{visited:?}");
 
@@ -409,11 +451,11 @@ mod tests {
         .unwrap();
 
         let mut rope = Rope::new(10);
-        let mut visited = vec![(0, 0)];
+        let mut visited = Set::from([Point::ORIGIN]);
         solution
             .moves
             .iter()
-            .for_each(|&move_| visited.extend(rope.make_move(move_)));
+            .for_each(|&move_| rope.make_move(move_, &mut visited));
 
         assert_eq!(15, visited.len(), "{visited:?}");
     }
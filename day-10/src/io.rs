@@ -0,0 +1,35 @@
+//! Reading the program line by line.
+
+use std::{error, io::BufRead, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{read, FromReader, TrySolve};
+
+use crate::{Program, Solution};
+
+impl FromStr for Solution {
+    type Err = Box<dyn error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s)?)
+    }
+}
+
+impl FromReader for Program {
+    fn from_reader(reader: impl BufRead) -> common::Result<Self> {
+        let mut program = Self::default();
+        read::lines(reader, |line| program.line(line))?;
+
+        Ok(program)
+    }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.solve_or_error(part)
+    }
+
+    fn day_number() -> u32 {
+        10
+    }
+}
@@ -1,24 +1,46 @@
-use std::{error::Error, str::FromStr};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use advent_utils::{Part, Solver};
-use color_eyre::{eyre::eyre, Report};
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use common::{parse::Input, trace, Answer, Error, Part, TrySolve};
+use grid::{Grid, Point};
 
 #[derive(Debug)]
 pub struct Solution {
-    cpu: CPU,
     instructions: Vec<Instruction>,
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
+        let instructions = Input::new(s)
+            .lines()
+            .map(Instruction::parse)
+            .collect::<common::Result<_>>()?;
+
+        Ok(Self { instructions })
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let instructions = s.lines().map(str::parse).collect::<Result<_, _>>()?;
+/// Writes the program back in the input format.
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for instruction in &self.instructions {
+            writeln!(f, "{instruction}")?;
+        }
 
-        Ok(Self {
-            instructions,
-            cpu: CPU::new(),
-        })
+        Ok(())
     }
 }
 
@@ -40,39 +62,93 @@ impl CPU {
         }
     }
 
-    fn retire(&mut self, instruction: Instruction) {
-        if self.current_instruction.is_none() {
-            self.current_instruction = Some(instruction);
-            self.instruction_running_for = 0;
-        } else {
-            panic!("invalid operation")
+    fn retire(&mut self, instruction: Instruction) -> common::Result<()> {
+        if self.current_instruction.is_some() {
+            return Err(Error::invalid_state(format!(
+                "{instruction} retired while another instruction runs"
+            )));
         }
+
+        self.current_instruction = Some(instruction);
+        self.instruction_running_for = 0;
+
+        Ok(())
     }
 
-    fn try_cycle(&mut self) -> Option<()> {
-        match self.current_instruction.take()? {
+    /// Runs a cycle of the current instruction, `false` if there is none.
+    fn try_cycle(&mut self) -> common::Result<bool> {
+        let Some(instruction) = self.current_instruction.take() else {
+            return Ok(false);
+        };
+
+        match instruction {
             Instruction::Noop => {}
-            instr @ Instruction::Addx(x) => {
+            Instruction::Addx(x) => {
                 self.instruction_running_for += 1;
                 if self.instruction_running_for < 2 {
-                    self.current_instruction = Some(instr);
+                    self.current_instruction = Some(instruction);
                 } else {
-                    self.x += x;
+                    self.x = self.x.checked_add(x).ok_or_else(|| {
+                        Error::invalid_state(format!(
+                            "register overflows at cycle {}",
+                            self.num_cycle
+                        ))
+                    })?;
                 }
             }
         }
 
         self.num_cycle += 1;
 
-        Some(())
+        Ok(true)
+    }
+
+    /// Runs a cycle, retiring the next instruction once the current one is done.
+    fn cycle(
+        &mut self,
+        instructions: &mut impl Iterator<Item = Instruction>,
+    ) -> common::Result<()> {
+        if self.try_cycle()? {
+            return Ok(());
+        }
+
+        let instruction = instructions
+            .next()
+            .ok_or_else(|| Error::unsolvable("not enough instructions"))?;
+        self.retire(instruction)?;
+        if !self.try_cycle()? {
+            return Err(Error::invalid_state(format!(
+                "retired {instruction} didn't run"
+            )));
+        }
+
+        Ok(())
     }
 
     fn current_cycle(&self) -> usize {
         self.num_cycle
     }
 
-    fn current_power(&self) -> i64 {
-        self.x * self.num_cycle as i64
+    fn current_power(&self) -> common::Result<i64> {
+        i64::try_from(self.num_cycle)
+            .ok()
+            .and_then(|cycle| self.x.checked_mul(cycle))
+            .ok_or_else(|| {
+                Error::invalid_state(format!(
+                    "signal strength overflows at cycle {}",
+                    self.num_cycle
+                ))
+            })
+    }
+
+    /// Traces the register during the current cycle.
+    fn trace(&self) {
+        trace::event("cycle", || {
+            vec![
+                ("cycle", self.num_cycle.to_string()),
+                ("x", self.x.to_string()),
+            ]
+        });
     }
 }
 
@@ -82,66 +158,118 @@ pub enum Instruction {
     Noop,
 }
 
-impl FromStr for Instruction {
-    type Err = Report;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match &s[..4] {
-            "addx" => Ok(Self::Addx(s[5..].parse()?)),
-            "noop" => Ok(Self::Noop),
-            _ => Err(eyre!("unknown instruction: {s}")),
+impl Instruction {
+    /// `addx 3` or `noop`
+    fn parse(line: Input<'_>) -> common::Result<Self> {
+        match line.split_once(" ") {
+            Ok((name, x)) if name.as_str() == "addx" => Ok(Self::Addx(x.number()?)),
+            _ if line.as_str() == "noop" => Ok(Self::Noop),
+            _ => Err(line.error("unknown instruction")),
         }
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
-        let mut cpu = self.cpu.clone();
-        let mut instructions = self.instructions.iter();
-        let mut total_power = 0;
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Addx(x) => write!(f, "addx {x}"),
+            Self::Noop => write!(f, "noop"),
+        }
+    }
+}
 
-        match part {
-            Part::One => {
-                while cpu.num_cycle <= 220 {
-                    if cpu.current_cycle() % 40 == 20 {
-                        total_power += cpu.current_power() as i64;
-                    }
+/// Runs the program for the 240 cycles the parts look at.
+fn run(instructions: impl IntoIterator<Item = Instruction>, part: Part) -> common::Result<Answer> {
+    let mut cpu = CPU::new();
+    let mut instructions = instructions.into_iter();
+    let mut total_power: i64 = 0;
 
-                    if cpu.try_cycle().is_none() {
-                        cpu.retire(*instructions.next().expect("not enough instructions"));
-                        cpu.try_cycle().expect("failed to run retired instruction");
-                    }
+    match part {
+        Part::One => {
+            while cpu.num_cycle <= 220 {
+                cpu.trace();
+                if cpu.current_cycle() % 40 == 20 {
+                    total_power = total_power
+                        .checked_add(cpu.current_power()?)
+                        .ok_or_else(|| Error::invalid_state("total power overflows"))?;
                 }
 
-                format!("total power is {total_power}")
+                cpu.cycle(&mut instructions)?;
             }
-            Part::Two => {
-                let mut result = String::with_capacity(250);
-
-                while cpu.num_cycle <= 240 {
-                    if cpu.x.abs_diff((cpu.current_cycle() - 1) as i64 % 40) <= 1 {
-                        result.push('#');
-                    } else {
-                        result.push('.');
-                    }
-
-                    if cpu.try_cycle().is_none() {
-                        cpu.retire(*instructions.next().expect("not enough instructions"));
-                        cpu.try_cycle().expect("failed to run retired instruction");
-                    }
-
-                    if cpu.current_cycle() % 40 == 1 {
-                        result.push('\n');
-                    }
-                }
 
-                format!("CRT reads:\n{result}")
+            Ok(total_power.into())
+        }
+        Part::Two => {
+            let mut screen = Grid::new(40, 6, false);
+
+            while cpu.num_cycle <= 240 {
+                cpu.trace();
+                let pixel = (cpu.current_cycle() - 1) as i64;
+                let pixel = Point::new(pixel % 40, pixel / 40);
+                screen[pixel] = cpu.x.abs_diff(pixel.x) <= 1;
+
+                cpu.cycle(&mut instructions)?;
             }
+
+            let screen = screen.render(|&lit| if lit { '#' } else { '.' });
+            Ok(Answer::Grid(screen.lines().map(str::to_owned).collect()))
+        }
+    }
+}
+
+fn describe(part: &Part, answer: &Answer) -> String {
+    match part {
+        Part::One => format!("total power is {answer}"),
+        Part::Two => format!("CRT reads:\n{answer}"),
+    }
+}
+
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
+        run(self.instructions.iter().copied(), part)
+    }
+
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
+    }
+}
+
+/// Start of the program, kept while reading it: every instruction takes at least a cycle, so the
+/// ones after the first 240 are never run.
+#[derive(Debug)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    const MAX_LEN: usize = 240;
+
+    /// Keeps the instruction of the next line, if it may be run.
+    pub fn line(&mut self, line: Input<'_>) -> common::Result<()> {
+        let instruction = Instruction::parse(line)?;
+        if self.instructions.len() < Self::MAX_LEN {
+            self.instructions.push(instruction);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Program {
+    fn default() -> Self {
+        Self {
+            instructions: Vec::with_capacity(Self::MAX_LEN),
         }
     }
+}
+
+impl TrySolve for Program {
+    fn try_solve(&self, part: Part) -> common::Result<Answer> {
+        run(self.instructions.iter().copied(), part)
+    }
 
-    fn day_number() -> u32 {
-        10
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        describe(part, answer)
     }
 }
 
@@ -149,13 +277,15 @@ impl Solver for Solution {
 mod tests {
     use super::*;
 
+    use common::FromReader;
+
     #[test]
     fn test_example() {
-        let solution: Solution = include_str!("../example.txt").parse().unwrap();
+        let solution: Solution = include_str!("../examples/example.txt").parse().unwrap();
 
-        assert_eq!(solution.solve(Part::One), "total power is 13140");
+        assert_eq!(solution.try_solve(Part::One), Ok(Answer::Number(13140)));
         assert_eq!(
-            solution.solve(Part::Two),
+            solution.solve_or_error(Part::Two),
             indoc::indoc! {
                 "CRT reads:
                 ##..##..##..##..##..##..##..##..##..##..
@@ -168,4 +298,35 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_trace() {
+        let solution: Solution = include_str!("../examples/example.txt").parse().unwrap();
+        let (_, events) = common::trace::capture(|| solution.try_solve(Part::One));
+
+        assert_eq!(events.len(), 220);
+        assert!(events.iter().all(|event| event.step == "cycle"));
+        // the example lists X during the interesting cycles
+        for (cycle, x) in [(20, "21"), (60, "19"), (220, "18")] {
+            assert_eq!(
+                events[cycle - 1].field("cycle"),
+                Some(cycle.to_string().as_str())
+            );
+            assert_eq!(events[cycle - 1].field("x"), Some(x));
+        }
+    }
+
+    #[test]
+    fn test_read() {
+        let input = include_str!("../examples/example.txt");
+        let program = Program::from_reader(input.as_bytes()).unwrap();
+        let solution: Solution = input.parse().unwrap();
+
+        assert_eq!(program.try_solve(Part::One), solution.try_solve(Part::One));
+        assert_eq!(program.try_solve(Part::Two), solution.try_solve(Part::Two));
+
+        let long = [input; 3].join("\n");
+        let program = Program::from_reader(long.as_bytes()).unwrap();
+        assert_eq!(program.instructions.len(), Program::MAX_LEN);
+    }
 }
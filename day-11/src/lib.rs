@@ -1,25 +1,50 @@
-use std::{cmp::Reverse, error::Error, str::FromStr};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use advent_utils::{Part, Solver};
-use color_eyre::{eyre::eyre, Report};
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod io;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Reverse;
+
+use common::{parse::Input, trace, Answer, Error, Part, Result, TrySolve};
 
 #[derive(Debug)]
 pub struct Solution {
     monkeys: Vec<Monkey>,
 }
 
-impl FromStr for Solution {
-    type Err = Box<dyn Error>;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let monkeys = s.split("\n\n").map(str::parse).collect::<Result<_, _>>()?;
+impl Solution {
+    pub fn parse(s: &str) -> common::Result<Self> {
+        let monkeys: Vec<Monkey> = Input::new(s)
+            .records("\n\n")
+            .map(Monkey::parse)
+            .collect::<common::Result<_>>()?;
+
+        for (i, monkey) in monkeys.iter().enumerate() {
+            if let Some(target) = [monkey.if_true, monkey.if_false]
+                .into_iter()
+                .find(|&target| target >= monkeys.len())
+            {
+                return Err(Error::invalid_state(format!(
+                    "monkey {i} throws to monkey {target}, but there are only {} monkeys",
+                    monkeys.len()
+                )));
+            }
+        }
 
         Ok(Self { monkeys })
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl TrySolve for Solution {
+    fn try_solve(&self, part: Part) -> Result<Answer> {
         let mut monkeys = self.monkeys.clone();
         let n_rounds = match part {
             Part::One => 20,
@@ -29,12 +54,19 @@ impl Solver for Solution {
             Part::One => 3,
             Part::Two => 1,
         };
-        let modulo =
-            lcm(monkeys.iter().map(|m| m.divisible_by)).expect("no monkeys in the game :(");
+        let modulo = lcm(monkeys.iter().map(|m| m.divisible_by))?;
 
-        for _ in 0..n_rounds {
+        for round in 1..=n_rounds {
             for i in 0..monkeys.len() {
-                for (new_monkey, item) in monkeys[i].make_move(divide_by, modulo) {
+                for (new_monkey, item) in monkeys[i].make_move(divide_by, modulo)? {
+                    trace::event("throw", || {
+                        vec![
+                            ("round", round.to_string()),
+                            ("from", i.to_string()),
+                            ("to", new_monkey.to_string()),
+                            ("worry", item.to_string()),
+                        ]
+                    });
                     monkeys[new_monkey].items.push(item);
                 }
             }
@@ -42,14 +74,18 @@ impl Solver for Solution {
 
         monkeys.sort_unstable_by_key(|m| Reverse(m.business));
 
-        format!(
-            "monkey business is {}",
-            monkeys[0].business * monkeys[1].business
-        )
+        match &monkeys[..] {
+            [first, second, ..] => first
+                .business
+                .checked_mul(second.business)
+                .ok_or_else(|| Error::unsolvable("monkey business overflows"))
+                .and_then(Answer::try_from),
+            _ => Err(Error::unsolvable("monkey business needs two monkeys")),
+        }
     }
 
-    fn day_number() -> u32 {
-        11
+    fn describe(&self, _part: &Part, answer: &Answer) -> String {
+        format!("monkey business is {answer}")
     }
 }
 
@@ -64,68 +100,54 @@ struct Monkey {
 }
 
 impl Monkey {
-    fn make_move(&mut self, divide_by: u64, modulo: u64) -> Vec<(usize, u64)> {
+    fn make_move(&mut self, divide_by: u64, modulo: u64) -> Result<Vec<(usize, u64)>> {
         self.items
             .drain(..)
             .map(|item| {
                 self.business += 1;
 
-                let new_item = ((self.operation.apply(item) / divide_by) % modulo) as u64;
+                let new_item = self.operation.apply(item).ok_or_else(|| {
+                    Error::invalid_state(format!("worry level of {item} overflows"))
+                })? / divide_by
+                    % modulo;
 
-                if new_item % self.divisible_by == 0 {
-                    (self.if_true, new_item)
+                if new_item.is_multiple_of(self.divisible_by) {
+                    Ok((self.if_true, new_item))
                 } else {
-                    (self.if_false, new_item)
+                    Ok((self.if_false, new_item))
                 }
             })
             .collect()
     }
 }
 
-impl FromStr for Monkey {
-    type Err = Report;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let err = || eyre!("unknown monkey format: {s}");
+impl Monkey {
+    fn parse(block: Input<'_>) -> Result<Self> {
+        let mut lines = block.lines();
 
-        let (_, rest) = s.split_once('\n').ok_or_else(err)?;
+        // Monkey 0:
+        lines.tagged("Monkey ")?;
 
         // Starting items: 1, 5
-        let (items_str, rest) = rest
-            .strip_prefix("  Starting items: ")
-            .and_then(|s| s.split_once('\n'))
-            .ok_or_else(err)?;
-        let items = items_str
-            .split(", ")
-            .map(str::parse)
-            .collect::<Result<_, _>>()?;
-
-        // Operation: old * 5
-        let (operation_str, rest) = rest
-            .strip_prefix("  Operation: new = ")
-            .and_then(|s| s.split_once('\n'))
-            .ok_or_else(err)?;
-        let operation = operation_str.parse()?;
-
-        // Operation: old * 5
-        let (test_str, rest) = rest
-            .strip_prefix("  Test: divisible by ")
-            .and_then(|s| s.split_once('\n'))
-            .ok_or_else(err)?;
-        let divisible_by = test_str.parse()?;
+        let items = lines.tagged("  Starting items: ")?.numbers(", ")?;
+
+        // Operation: new = old * 5
+        let operation = Operation::parse(lines.tagged("  Operation: new = ")?)?;
+
+        // Test: divisible by 3
+        let test = lines.tagged("  Test: divisible by ")?;
+        let divisible_by = match test.number()? {
+            0 => return Err(test.error("divisible by zero")),
+            divisible_by => divisible_by,
+        };
 
         // If true: throw to monkey 3
-        let (if_true, rest) = rest
-            .strip_prefix("    If true: throw to monkey ")
-            .and_then(|s| s.split_once('\n'))
-            .ok_or_else(err)?;
-        let if_true = if_true.parse()?;
+        let if_true = lines.tagged("    If true: throw to monkey ")?.number()?;
 
         // If false: throw to monkey 6
-        let if_false = rest
-            .strip_prefix("    If false: throw to monkey ")
-            .ok_or_else(err)?
-            .parse()?;
+        let if_false = lines.tagged("    If false: throw to monkey ")?.number()?;
+
+        lines.end()?;
 
         Ok(Self {
             operation,
@@ -146,37 +168,35 @@ struct Operation {
 }
 
 impl Operation {
-    fn apply(&self, old: u64) -> u64 {
+    /// New worry level, `None` on overflow.
+    fn apply(&self, old: u64) -> Option<u64> {
         let left = match self.left {
             Operand::Item => old,
             Operand::Const(n) => n,
-        } as u64;
+        };
 
         let right = match self.right {
             Operand::Item => old,
             Operand::Const(n) => n,
-        } as u64;
+        };
 
         match self.operator {
-            Operator::Add => left + right,
-            Operator::Mul => left * right,
+            Operator::Add => left.checked_add(right),
+            Operator::Mul => left.checked_mul(right),
         }
     }
 }
 
-impl FromStr for Operation {
-    type Err = Report;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let err = || eyre!("unknown operation format: {s}");
-
-        let (left, rest) = s.split_once(' ').ok_or_else(err)?;
-        let (operator, right) = rest.split_once(' ').ok_or_else(err)?;
+impl Operation {
+    /// `old * 5`
+    fn parse(s: Input<'_>) -> Result<Self> {
+        let (left, rest) = s.split_once(" ")?;
+        let (operator, right) = rest.split_once(" ")?;
 
         Ok(Self {
-            left: left.parse()?,
-            right: right.parse()?,
-            operator: operator.parse()?,
+            left: Operand::parse(left)?,
+            right: Operand::parse(right)?,
+            operator: Operator::parse(operator)?,
         })
     }
 }
@@ -187,13 +207,11 @@ enum Operand {
     Const(u64),
 }
 
-impl FromStr for Operand {
-    type Err = Report;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
+impl Operand {
+    fn parse(s: Input<'_>) -> Result<Self> {
+        match s.as_str() {
             "old" => Ok(Self::Item),
-            n => Ok(Self::Const(n.parse()?)),
+            _ => Ok(Self::Const(s.number()?)),
         }
     }
 }
@@ -204,20 +222,25 @@ enum Operator {
     Add,
 }
 
-impl FromStr for Operator {
-    type Err = Report;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
+impl Operator {
+    fn parse(s: Input<'_>) -> Result<Self> {
+        match s.as_str() {
             "+" => Ok(Self::Add),
             "*" => Ok(Self::Mul),
-            _ => Err(eyre!("unknown operator: {s}")),
+            _ => Err(s.error("unknown operator")),
         }
     }
 }
 
-fn lcm(nums: impl Iterator<Item = u64>) -> Option<u64> {
+fn lcm(mut nums: impl Iterator<Item = u64>) -> Result<u64> {
     use gcd::euclid_u64 as gcd;
 
-    nums.reduce(|a, b| a * b / gcd(a, b))
+    let first = nums
+        .next()
+        .ok_or_else(|| Error::unsolvable("no monkeys in the game :("))?;
+    nums.try_fold(first, |a, b| {
+        (a / gcd(a, b))
+            .checked_mul(b)
+            .ok_or_else(|| Error::unsolvable("monkey divisors overflow"))
+    })
 }
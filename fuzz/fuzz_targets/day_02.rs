@@ -0,0 +1,10 @@
+#![no_main]
+
+use std::str::FromStr;
+
+use libfuzzer_sys::fuzz_target;
+
+// parsing should either succeed or fail, never panic
+fuzz_target!(|data: &[u8]| {
+    let _ = day_02::Solution::from_str(&String::from_utf8_lossy(data));
+});
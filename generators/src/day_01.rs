@@ -0,0 +1,18 @@
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, Rng};
+
+/// `n_elves` groups of calorie counts, separated by empty lines.
+pub(crate) fn generate(rng: &mut StdRng, n_elves: usize) -> String {
+    let mut input = String::new();
+    for elf in 0..n_elves {
+        if elf > 0 {
+            input.push('\n');
+        }
+        for _ in 0..rng.gen_range(1..=15) {
+            writeln!(input, "{}", rng.gen_range(1000..=60000)).unwrap();
+        }
+    }
+
+    input
+}
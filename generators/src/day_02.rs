@@ -0,0 +1,15 @@
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, seq::SliceRandom};
+
+/// `n_rounds` lines of opponent's move and the second column, e.g. `A Y`.
+pub(crate) fn generate(rng: &mut StdRng, n_rounds: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..n_rounds {
+        let left = ['A', 'B', 'C'].choose(rng).unwrap();
+        let right = ['X', 'Y', 'Z'].choose(rng).unwrap();
+        writeln!(input, "{left} {right}").unwrap();
+    }
+
+    input
+}
@@ -0,0 +1,41 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+const ITEM_TYPES: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `n_groups` groups of three rucksacks.
+///
+/// Compartments of every rucksack share exactly one item type, and all rucksacks of a group
+/// share exactly one (the badge): every rucksack gets its own item types plus the badge, with
+/// one of them in both compartments and the others split between the two.
+pub(crate) fn generate(rng: &mut StdRng, n_groups: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..n_groups {
+        let mut types = ITEM_TYPES.to_vec();
+        types.shuffle(rng);
+        let (badge, own_types) = types.split_first().unwrap();
+
+        for own in own_types.chunks_exact(own_types.len() / 3) {
+            let mut types = own.to_vec();
+            types.push(*badge);
+            types.shuffle(rng);
+
+            let (shared, rest) = types.split_first().unwrap();
+            let (left, right) = rest.split_at(rest.len() / 2);
+            let compartment_len = right.len() + 1 + rng.gen_range(0..=8);
+
+            for only_here in [left, right] {
+                let mut compartment = vec![*shared];
+                compartment.extend(only_here);
+                while compartment.len() < compartment_len {
+                    compartment.push(*only_here.choose(rng).unwrap());
+                }
+                compartment.shuffle(rng);
+
+                input.extend(compartment.into_iter().map(char::from));
+            }
+            input.push('\n');
+        }
+    }
+
+    input
+}
@@ -0,0 +1,20 @@
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, Rng};
+
+/// `n_pairs` lines of two section ranges, e.g. `2-4,6-8`.
+pub(crate) fn generate(rng: &mut StdRng, n_pairs: usize) -> String {
+    let mut range = || {
+        let start = rng.gen_range(1..=99);
+
+        (start, rng.gen_range(start..=99))
+    };
+
+    let mut input = String::new();
+    for _ in 0..n_pairs {
+        let (left, right) = (range(), range());
+        writeln!(input, "{}-{},{}-{}", left.0, left.1, right.0, right.1).unwrap();
+    }
+
+    input
+}
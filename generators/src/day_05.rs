@@ -0,0 +1,40 @@
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, Rng};
+
+/// Drawing of up to 9 stacks of crates, followed by `n_moves` moves which are all possible.
+pub(crate) fn generate(rng: &mut StdRng, n_moves: usize) -> String {
+    let n_stacks = rng.gen_range(2..=9);
+    let mut heights: Vec<usize> = (0..n_stacks).map(|_| rng.gen_range(0..=8)).collect();
+    heights[0] = heights[0].max(1);
+
+    let mut input = String::new();
+    for level in (0..heights.iter().copied().max().unwrap()).rev() {
+        let row: Vec<_> = heights
+            .iter()
+            .map(|&height| {
+                if height > level {
+                    format!("[{}]", rng.gen_range('A'..='Z'))
+                } else {
+                    "   ".to_owned()
+                }
+            })
+            .collect();
+        writeln!(input, "{}", row.join(" ")).unwrap();
+    }
+    let numbers: Vec<_> = (1..=n_stacks).map(|n| format!(" {n} ")).collect();
+    writeln!(input, "{}\n", numbers.join(" ")).unwrap();
+
+    for _ in 0..n_moves {
+        let non_empty: Vec<_> = (0..n_stacks).filter(|&i| heights[i] > 0).collect();
+        let from = non_empty[rng.gen_range(0..non_empty.len())];
+        let to = (from + rng.gen_range(1..n_stacks)) % n_stacks;
+        let n_to_move = rng.gen_range(1..=heights[from]);
+
+        heights[from] -= n_to_move;
+        heights[to] += n_to_move;
+        writeln!(input, "move {n_to_move} from {} to {}", from + 1, to + 1).unwrap();
+    }
+
+    input
+}
@@ -0,0 +1,18 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// Datastream of `len` characters with both markers at the very end.
+///
+/// Everything before the markers uses only 3 letters, so the whole stream has to be scanned.
+pub(crate) fn generate(rng: &mut StdRng, len: usize) -> String {
+    let mut letters: Vec<_> = ('a'..='z').collect();
+    letters.shuffle(rng);
+    let (marker, filler) = letters.split_at(14);
+
+    let mut stream: String = (0..len - marker.len())
+        .map(|_| filler[rng.gen_range(0..3)])
+        .collect();
+    stream.extend(marker);
+    stream.push('\n');
+
+    stream
+}
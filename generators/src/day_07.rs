@@ -0,0 +1,53 @@
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, Rng};
+
+/// Log of `cd`/`ls` commands walking a random tree of `n_entries` files and directories.
+///
+/// File sizes are chosen so that the total is between half of the disk and the disk size, so
+/// there is both enough space and not enough of it for the update.
+pub(crate) fn generate(rng: &mut StdRng, n_entries: usize) -> String {
+    const MAX_TOTAL_SIZE: usize = 70_000_000;
+
+    // parent of every entry, the root (which is not counted) is 0
+    let mut children = vec![vec![]; n_entries + 1];
+    let mut is_dir = vec![true];
+    for entry in 1..=n_entries {
+        let dirs: Vec<_> = (0..entry).filter(|&i| is_dir[i]).collect();
+        children[dirs[rng.gen_range(0..dirs.len())]].push(entry);
+        is_dir.push(rng.gen_bool(0.3));
+    }
+
+    let n_files = is_dir.iter().filter(|&&is_dir| !is_dir).count();
+    let max_file_size = MAX_TOTAL_SIZE / n_files.max(1);
+    let mut input = String::new();
+    let mut stack = vec![Some(0)];
+    while let Some(dir) = stack.pop() {
+        let Some(dir) = dir else {
+            input.push_str("$ cd ..\n");
+            continue;
+        };
+
+        let name = if dir == 0 {
+            "/".to_owned()
+        } else {
+            format!("d{dir}")
+        };
+        writeln!(input, "$ cd {name}").unwrap();
+        // the parser rejects listings with no entries
+        if !children[dir].is_empty() {
+            input.push_str("$ ls\n");
+        }
+        for &child in &children[dir] {
+            if is_dir[child] {
+                writeln!(input, "dir d{child}").unwrap();
+                stack.extend([None, Some(child)]);
+            } else {
+                let size = rng.gen_range(max_file_size / 2..=max_file_size);
+                writeln!(input, "{size} f{child}.txt").unwrap();
+            }
+        }
+    }
+
+    input
+}
@@ -0,0 +1,12 @@
+use rand::{rngs::StdRng, Rng};
+
+/// Square grid of tree heights with `side` trees per side.
+pub(crate) fn generate(rng: &mut StdRng, side: usize) -> String {
+    let mut input = String::with_capacity(side * (side + 1));
+    for _ in 0..side {
+        input.extend((0..side).map(|_| char::from(b'0' + rng.gen_range(0..=9))));
+        input.push('\n');
+    }
+
+    input
+}
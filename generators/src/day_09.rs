@@ -0,0 +1,14 @@
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// `n_moves` head moves, e.g. `R 4`.
+pub(crate) fn generate(rng: &mut StdRng, n_moves: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..n_moves {
+        let direction = ['L', 'R', 'U', 'D'].choose(rng).unwrap();
+        writeln!(input, "{direction} {}", rng.gen_range(1..=20)).unwrap();
+    }
+
+    input
+}
@@ -0,0 +1,17 @@
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, Rng};
+
+/// Program of `n_instructions` `noop` and `addx` instructions.
+pub(crate) fn generate(rng: &mut StdRng, n_instructions: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..n_instructions {
+        if rng.gen_bool(0.3) {
+            input.push_str("noop\n");
+        } else {
+            writeln!(input, "addx {}", rng.gen_range(-20..=20)).unwrap();
+        }
+    }
+
+    input
+}
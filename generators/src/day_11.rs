@@ -0,0 +1,40 @@
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// Notes on `n_monkeys` monkeys.
+///
+/// Tests only use primes up to 23, so worry levels stay within `u64` even for `old * old`.
+pub(crate) fn generate(rng: &mut StdRng, n_monkeys: usize) -> String {
+    const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+    let mut monkeys = vec![];
+    for monkey in 0..n_monkeys {
+        let items: Vec<_> = (0..rng.gen_range(1..=8))
+            .map(|_| rng.gen_range(50..=99).to_string())
+            .collect();
+        let operation = match rng.gen_range(0..5) {
+            0 => "old * old".to_owned(),
+            1 | 2 => format!("old * {}", rng.gen_range(2..=19)),
+            _ => format!("old + {}", rng.gen_range(1..=8)),
+        };
+        let mut other = || (monkey + rng.gen_range(1..n_monkeys)) % n_monkeys;
+        let (if_true, if_false) = (other(), other());
+
+        let mut notes = String::new();
+        writeln!(notes, "Monkey {monkey}:").unwrap();
+        writeln!(notes, "  Starting items: {}", items.join(", ")).unwrap();
+        writeln!(notes, "  Operation: new = {operation}").unwrap();
+        writeln!(
+            notes,
+            "  Test: divisible by {}",
+            DIVISORS.choose(rng).unwrap()
+        )
+        .unwrap();
+        writeln!(notes, "    If true: throw to monkey {if_true}").unwrap();
+        write!(notes, "    If false: throw to monkey {if_false}").unwrap();
+        monkeys.push(notes);
+    }
+
+    monkeys.join("\n\n")
+}
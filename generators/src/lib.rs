@@ -0,0 +1,153 @@
+//! Random puzzle inputs of any size, valid in the format of each day.
+//!
+//! ```
+//! let generator = generators::find(1).unwrap();
+//! let input = generator.generate(10, 42);
+//!
+//! assert_eq!(input.split("\n\n").count(), 10);
+//! assert_eq!(input, generator.generate(10, 42));
+//! ```
+
+use rand::{rngs::StdRng, SeedableRng};
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+
+type GenerateFn = fn(&mut StdRng, usize) -> String;
+
+pub struct Generator {
+    day: u32,
+    unit: &'static str,
+    min_size: usize,
+    generate: GenerateFn,
+}
+
+impl Generator {
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// What `size` counts, e.g. `lines`.
+    pub fn unit(&self) -> &'static str {
+        self.unit
+    }
+
+    /// Smallest size the day's solver accepts.
+    pub fn min_size(&self) -> usize {
+        self.min_size
+    }
+
+    /// Input of the given `size` (at least [`Self::min_size`]), same for the same `seed`.
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size.max(self.min_size))
+    }
+}
+
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        unit: "elves",
+        min_size: 3,
+        generate: day_01::generate,
+    },
+    Generator {
+        day: 2,
+        unit: "rounds",
+        min_size: 1,
+        generate: day_02::generate,
+    },
+    Generator {
+        day: 3,
+        unit: "groups of rucksacks",
+        min_size: 1,
+        generate: day_03::generate,
+    },
+    Generator {
+        day: 4,
+        unit: "pairs",
+        min_size: 1,
+        generate: day_04::generate,
+    },
+    Generator {
+        day: 5,
+        unit: "moves",
+        min_size: 1,
+        generate: day_05::generate,
+    },
+    Generator {
+        day: 6,
+        unit: "characters",
+        min_size: 14,
+        generate: day_06::generate,
+    },
+    Generator {
+        day: 7,
+        unit: "entries",
+        min_size: 1,
+        generate: day_07::generate,
+    },
+    Generator {
+        day: 8,
+        unit: "trees per side",
+        min_size: 1,
+        generate: day_08::generate,
+    },
+    Generator {
+        day: 9,
+        unit: "moves",
+        min_size: 1,
+        generate: day_09::generate,
+    },
+    Generator {
+        day: 10,
+        unit: "instructions",
+        min_size: 240,
+        generate: day_10::generate,
+    },
+    Generator {
+        day: 11,
+        unit: "monkeys",
+        min_size: 2,
+        generate: day_11::generate,
+    },
+];
+
+pub fn find(day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inputs_are_solvable() {
+        for generator in GENERATORS {
+            let day = solvers::find(generator.day).expect("generated day is solved");
+
+            for size in [generator.min_size, 2 * generator.min_size + 7, 100] {
+                for seed in 0..5 {
+                    let input = generator.generate(size, seed);
+                    let solver = day.parse(&input).unwrap_or_else(|err| {
+                        panic!("day {} failed to parse:\n{input}\n{err}", generator.day)
+                    });
+
+                    for part in day.part_numbers() {
+                        if let Err(err) = solver.try_solve(solvers::part(part).unwrap()) {
+                            panic!("day {} failed part {part}:\n{input}\n{err}", generator.day);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
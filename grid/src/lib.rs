@@ -0,0 +1,262 @@
+//! Dense two-dimensional [`Grid`] with [`Point`]s and [`Direction`]s on it, parsed from character
+//! maps of the puzzle inputs and rendered back to text.
+//!
+//! ```
+//! use grid::{Direction, Grid, Point};
+//!
+//! let grid = Grid::parse("123\n456\n", |c| c.to_digit(10).ok_or("not a digit")).unwrap();
+//!
+//! assert_eq!((grid.width(), grid.height()), (3, 2));
+//! assert_eq!(grid[Point::new(2, 1)], 6);
+//! let left: Vec<_> = grid.ray(Point::new(2, 1), Direction::Left).map(|(_, &n)| n).collect();
+//! assert_eq!(left, [5, 4]);
+//!
+//! let err = Grid::parse("12\n3x\n", |c| c.to_digit(10).ok_or("not a digit")).unwrap_err();
+//! assert_eq!(err.to_string(), r#"line 2, column 2: not a digit: "x""#);
+//! ```
+
+#![no_std]
+
+extern crate alloc;
+
+mod point;
+
+use alloc::{format, string::String, vec, vec::Vec};
+use core::ops::{Index, IndexMut};
+
+use common::{parse::Input, Result};
+
+pub use point::{Direction, Point};
+
+/// Cells of a rectangular grid stored row by row, `width` may differ from `height`.
+///
+/// Points outside of the grid are `None` for [`Grid::get`] and panic when indexing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` by `height` cells, all set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses a character map, a line per row, with a cell for every character.
+    ///
+    /// Characters which `cell` rejects are reported with its reason, as well as rows of different
+    /// widths.
+    pub fn parse<F, R>(s: &str, mut cell: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Result<T, R>,
+        R: Into<String>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in Input::new(s).lines() {
+            for (c, at) in line.chars() {
+                cells.push(cell(c).map_err(|reason| at.error(reason))?);
+            }
+
+            let row_width = line.as_str().chars().count();
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(
+                        line.error(format!("row of {row_width} cells in a grid {width} wide"))
+                    )
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(self.offset(point)?)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        let offset = self.offset(point)?;
+
+        self.cells.get_mut(offset)
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i64;
+
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` doesn't take zero, but a grid without columns has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Orthogonal neighbours of `point` within the grid, with their cells.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours()
+            .filter_map(|neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// Cells from `point` (not included) to the edge of the grid in `direction`.
+    pub fn ray(&self, point: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .ray(direction)
+            .map_while(|point| Some((point, self.get(point)?)))
+    }
+
+    /// Text with a line per row and a character per cell, the reverse of [`Grid::parse`].
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(&mut cell));
+            text.push('\n');
+        }
+
+        text
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+
+        Some(y * self.width + x)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.offset(point) {
+            Some(offset) => &self.cells[offset],
+            None => panic!(
+                "{point:?} is outside of {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.offset(point) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!(
+                "{point:?} is outside of {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn digits(s: &str) -> Result<Grid<u32>> {
+        Grid::parse(s, |c| c.to_digit(10).ok_or("not a digit"))
+    }
+
+    #[test]
+    fn test_parse_render() {
+        let grid = digits("1234\n5678\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        assert_eq!(grid.get(Point::new(3, 0)), Some(&4));
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(
+            grid.render(|&n| char::from_digit(n, 10).unwrap()),
+            "1234\n5678\n"
+        );
+
+        let empty = digits("").unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.render(|_| '#'), "");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            digits("12\n345\n").unwrap_err().to_string(),
+            r#"line 2, column 1: row of 3 cells in a grid 2 wide: "345""#
+        );
+        assert_eq!(
+            digits("12\n3ä\n").unwrap_err().to_string(),
+            r#"line 2, column 2: not a digit: "ä""#
+        );
+    }
+
+    #[test]
+    fn test_neighbours_and_rays() {
+        let grid = digits("123\n456\n789\n").unwrap();
+        let values = |cells: &mut dyn Iterator<Item = (Point, &u32)>| -> Vec<u32> {
+            cells.map(|(_, &n)| n).collect()
+        };
+
+        assert_eq!(values(&mut grid.neighbours(Point::new(1, 1))), [2, 8, 4, 6]);
+        assert_eq!(values(&mut grid.neighbours(Point::ORIGIN)), [4, 2]);
+        assert_eq!(
+            values(&mut grid.ray(Point::new(1, 2), Direction::Up)),
+            [5, 2]
+        );
+        assert_eq!(
+            values(&mut grid.ray(Point::new(2, 0), Direction::Right)),
+            []
+        );
+        assert_eq!(grid.points().count(), 9);
+        assert_eq!(grid.points().nth(4), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_index() {
+        let mut grid = Grid::new(3, 2, '.');
+        grid[Point::new(2, 1)] = '#';
+
+        assert_eq!(grid.render(|&c| c), "...\n..#\n");
+        assert!(grid.contains(Point::new(2, 1)));
+        assert!(!grid.contains(Point::new(3, 1)));
+    }
+
+    #[test]
+    #[should_panic = "is outside of 3x2 grid"]
+    fn test_index_outside() {
+        let grid = Grid::new(3, 2, '.');
+
+        let _ = grid[Point::new(0, 2)];
+    }
+}
@@ -0,0 +1,92 @@
+use core::{
+    iter,
+    ops::{Add, Mul, Sub},
+};
+
+/// Position on a plane with `y` growing downwards, as rows of a [`Grid`](crate::Grid) do.
+///
+/// Coordinates are signed, so a point may be off the grid or on an unbounded plane.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The adjacent point in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// Orthogonally adjacent points, in the order of [`Direction::ALL`].
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// Points in `direction`, starting with the adjacent one, without end.
+    pub fn ray(self, direction: Direction) -> impl Iterator<Item = Self> {
+        iter::successors(Some(self.step(direction)), move |point| {
+            Some(point.step(direction))
+        })
+    }
+
+    /// Whether `other` is the same point or adjacent to it, diagonally included.
+    pub fn touches(self, other: Self) -> bool {
+        self.x.abs_diff(other.x) <= 1 && self.y.abs_diff(other.y) <= 1
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Scales the point as a vector, e.g. an offset of [`Direction`] by a number of steps.
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, n: i64) -> Self {
+        Self::new(self.x * n, self.y * n)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+
+    /// Offset of the adjacent point in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::Right => Point::new(1, 0),
+        }
+    }
+}
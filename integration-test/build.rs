@@ -0,0 +1,182 @@
+//! Generates a test for every part in the workspace's `answers.toml` and for every example in
+//! `day-NN/examples`, see `src/lib.rs`.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+type Parts = BTreeMap<String, toml::Value>;
+type Answers = BTreeMap<String, Parts>;
+/// Example's input path and expected answers by test names.
+type Examples = BTreeMap<String, (PathBuf, Vec<(u8, String)>)>;
+
+/// Numbers of parts with their expected answers, rendered the same way as `common::Answer`, e.g.
+/// `part-1 = 'CMZ'` becomes `(1, "CMZ")` and `part-2 = 42` becomes `(2, "42")`.
+fn part_numbers(parts: &Parts, context: &str) -> Result<Vec<(u8, String)>, Box<dyn Error>> {
+    parts
+        .iter()
+        .map(|(part, expected)| {
+            let number = part
+                .strip_prefix("part-")
+                .and_then(|number| number.parse().ok())
+                .ok_or_else(|| format!("invalid part `{part}` in {context}"))?;
+            let expected = match expected {
+                toml::Value::String(text) => text.clone(),
+                toml::Value::Integer(n) => n.to_string(),
+                _ => return Err(format!("`{part}` in {context} is not a string or integer").into()),
+            };
+
+            Ok((number, expected))
+        })
+        .collect()
+}
+
+fn answers_tests() -> Result<String, Box<dyn Error>> {
+    println!("cargo:rerun-if-changed=../answers.toml");
+
+    let answers: Answers = toml::from_str(&fs::read_to_string("../answers.toml")?)?;
+
+    let mut tests = String::new();
+    let mut answered = String::new();
+    for (day, parts) in &answers {
+        let number: u32 = day
+            .strip_prefix("day-")
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| format!("invalid day `{day}` in answers.toml"))?;
+
+        writeln!(tests, "mod day_{number:02} {{")?;
+        for (part_number, expected) in part_numbers(parts, &format!("`{day}` of answers.toml"))? {
+            writeln!(tests, "    #[test]")?;
+            writeln!(tests, "    fn part_{part_number}() {{")?;
+            writeln!(
+                tests,
+                "        super::check({number}, {part_number}, {expected:?});"
+            )?;
+            writeln!(tests, "    }}")?;
+            writeln!(answered, "    ({number}, {part_number}),")?;
+        }
+        writeln!(tests, "}}")?;
+    }
+
+    Ok(format!(
+        "const ANSWERED: &[(u32, u8)] = &[\n{answered}];\n\n{tests}"
+    ))
+}
+
+/// Test name for the example file, e.g. `larger` for `larger.txt` or `case_2` for `2.txt`.
+fn test_name(stem: &str) -> String {
+    let name: String = stem
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' => c,
+            'A'..='Z' => c.to_ascii_lowercase(),
+            _ => '_',
+        })
+        .collect();
+
+    match name.starts_with(|c: char| c.is_ascii_digit()) {
+        true => format!("case_{name}"),
+        false => name,
+    }
+}
+
+/// `NAME.txt` inputs of `day-NN/examples` paired with `NAME.expected` answers, keyed by test names.
+fn day_examples(examples_dir: &Path) -> Result<Examples, Box<dyn Error>> {
+    let mut examples = BTreeMap::new();
+    for entry in fs::read_dir(examples_dir)? {
+        let path = entry?.path();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        let paired = match extension {
+            Some("txt") => path.with_extension("expected"),
+            Some("expected") => path.with_extension("txt"),
+            _ => continue,
+        };
+        if !paired.is_file() {
+            return Err(format!("{} has no pair {}", path.display(), paired.display()).into());
+        }
+        if extension != Some("txt") {
+            continue;
+        }
+
+        let expected: Parts = toml::from_str(&fs::read_to_string(&paired)?)
+            .map_err(|err| format!("invalid {}: {err}", paired.display()))?;
+        let expected = part_numbers(&expected, &paired.display().to_string())?;
+        if expected.is_empty() {
+            return Err(format!("{} has no answers", paired.display()).into());
+        }
+
+        let stem = path.file_stem().and_then(|stem| stem.to_str());
+        let name = test_name(stem.ok_or_else(|| format!("invalid name {}", path.display()))?);
+        if examples.contains_key(&name) {
+            return Err(format!(
+                "{} has the same test name as another example",
+                path.display()
+            )
+            .into());
+        }
+
+        examples.insert(name, (path.canonicalize()?, expected));
+    }
+
+    Ok(examples)
+}
+
+fn examples_tests() -> Result<String, Box<dyn Error>> {
+    let mut days = BTreeMap::new();
+    for entry in fs::read_dir("..")? {
+        let path = entry?.path();
+        let number = path
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day-")?.parse::<u32>().ok());
+        let Some(number) = number else {
+            continue;
+        };
+
+        // directories are scanned entirely, so added examples are picked up as well
+        let examples_dir = path.join("examples");
+        if examples_dir.is_dir() {
+            println!("cargo:rerun-if-changed={}", examples_dir.display());
+            days.insert(number, day_examples(&examples_dir)?);
+        } else {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+    }
+
+    let mut tests = String::new();
+    writeln!(tests, "mod examples {{")?;
+    for (number, examples) in &days {
+        writeln!(tests, "    mod day_{number:02} {{")?;
+        for (name, (path, expected)) in examples {
+            let path = path
+                .to_str()
+                .ok_or_else(|| format!("non UTF-8 path {}", path.display()))?;
+
+            writeln!(tests, "        #[test]")?;
+            writeln!(tests, "        fn {name}() {{")?;
+            writeln!(tests, "            super::super::check_example(")?;
+            writeln!(tests, "                {number},")?;
+            writeln!(tests, "                include_str!({path:?}),")?;
+            writeln!(tests, "                &{expected:?},")?;
+            writeln!(tests, "            );")?;
+            writeln!(tests, "        }}")?;
+        }
+        writeln!(tests, "    }}")?;
+    }
+    writeln!(tests, "}}")?;
+
+    Ok(tests)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+
+    fs::write(out_dir.join("answers.rs"), answers_tests()?)?;
+    fs::write(out_dir.join("examples.rs"), examples_tests()?)?;
+
+    Ok(())
+}
@@ -1,33 +1,85 @@
+//! Checks answers of all days on their real inputs against `answers.toml`, and on the examples.
+//!
+//! There is a test for every part, e.g. `tests::day_07::part_2`, so `cargo test day_07` runs
+//! only one day. Run `run-all --check --bless` to record answers of a new part.
+//!
+//! Every `day-NN/examples/NAME.txt` gets a test too, e.g. `tests::examples::day_07::larger`,
+//! comparing answers with the ones in `NAME.expected`, which has the format of `answers.toml`:
+//!
+//! ```toml
+//! part-1 = 24000
+//! part-2 = 'CMZ'
+//! ```
+//!
+//! Answers are values rather than sentences: integers for numbers, strings for everything else
+//! (a grid with every row on its own line). Only listed parts are checked, so an example may be
+//! for a single part.
+//!
+//! Days are run in-process with `run-all`'s own logic, the binary itself is only checked by the
+//! ignored smoke test in `tests/binary.rs`.
+
 #[cfg(test)]
 mod tests {
+    use std::{collections::BTreeSet, path::Path};
+
     use pretty_assertions::assert_eq;
-    use std::process::Command;
+    use run_all::input::{Inputs, Layout};
+
+    /// Runs the part the same way `run-all` does, with inputs from the workspace.
+    fn check(day_number: u32, part_number: u8, expected: &str) {
+        let day = solvers::find(day_number).expect("day with an answer is solved");
+        let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let inputs = Inputs::new(Layout::Crates(workspace_dir), vec![]).unwrap();
+
+        let report = run_all::run(day, &inputs, &BTreeSet::from([part_number]), 1, false)
+            .unwrap_or_else(|err| panic!("day {day_number} failed: {err}"));
+
+        assert_eq!(report.parts.len(), 1);
+        match &report.parts[0].answer {
+            Ok(answer) => assert_eq!(answer.to_string(), expected),
+            Err(err) => panic!("day {day_number} failed part {part_number}: {err}"),
+        }
+    }
+
+    /// Runs the listed parts on the example, the same way `run-all` does.
+    fn check_example(day_number: u32, input: &str, expected: &[(u8, &str)]) {
+        let day = solvers::find(day_number).expect("day with examples is solved");
+        let parts = expected.iter().map(|&(part, _)| part).collect();
+
+        let report = run_all::run_on(day, input, &parts, 1, false)
+            .unwrap_or_else(|err| panic!("day {day_number} failed: {err}"));
+
+        let answers: Vec<_> = report
+            .parts
+            .iter()
+            .map(|part| match &part.answer {
+                Ok(answer) => (part.part, answer.to_string()),
+                Err(err) => panic!("day {day_number} failed part {}: {err}", part.part),
+            })
+            .collect();
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|&(part, answer)| (part, answer.to_owned()))
+            .collect();
+        assert_eq!(answers, expected);
+    }
+
+    // `ANSWERED` and a module with a test per part for every day, generated from `answers.toml`
+    include!(concat!(env!("OUT_DIR"), "/answers.rs"));
+
+    // `examples` module with a module of tests for every day with examples
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
 
     #[test]
-    fn test_expected_output() {
-        Command::new("cargo")
-            .current_dir("../")
-            .arg("build")
-            .arg("--release")
-            .arg("-p")
-            .arg("run-all")
-            .status()
-            .expect("failed to build");
-
-        // assert that all existing solutions are valid after any change
-        let output = Command::new("./target/release/run-all")
-            .current_dir("../")
-            .output()
-            .expect("failed to run all solutions");
-
-        assert_eq!(
-            String::from_utf8(output.stdout)
-                .unwrap()
-                .split('\n')
-                .collect::<Vec<_>>(),
-            include_str!("../reference.txt")
-                .split('\n')
-                .collect::<Vec<_>>(),
-        );
+    fn test_all_parts_have_answers() {
+        for day in solvers::DAYS {
+            for part in day.part_numbers() {
+                assert!(
+                    ANSWERED.contains(&(day.number(), part)),
+                    "no answer for day {} part {part} in answers.toml",
+                    day.number()
+                );
+            }
+        }
     }
 }
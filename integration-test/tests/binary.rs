@@ -0,0 +1,59 @@
+//! Smoke test of the `run-all` binary: builds it and checks all answers with `--check`.
+//!
+//! It's ignored by default, as it runs `cargo build --release` (which needs the network for the
+//! first build), run it with `cargo test -p integration-test -- --ignored`.
+
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+/// Builds `run-all` with the same cargo (and so the same `CARGO_TARGET_DIR`) as the tests,
+/// returning the path to the binary which cargo reports.
+fn build_run_all(workspace_dir: &Path) -> PathBuf {
+    let output = Command::new(env!("CARGO"))
+        .current_dir(workspace_dir)
+        .args([
+            "build",
+            "--release",
+            "-p",
+            "run-all",
+            "--message-format=json",
+        ])
+        .stderr(Stdio::inherit())
+        .output()
+        .expect("failed to run cargo");
+    assert!(output.status.success(), "failed to build run-all");
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|message| message["target"]["name"] == "run-all")
+        .find_map(|message| message["executable"].as_str().map(PathBuf::from))
+        .expect("cargo didn't report run-all executable")
+}
+
+#[test]
+#[ignore = "builds the release binary, run with `--ignored`"]
+fn test_binary_checks_all_answers() {
+    let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let run_all = build_run_all(&workspace_dir);
+
+    let output = Command::new(run_all)
+        .current_dir(&workspace_dir)
+        .env("BASE_PATH", &workspace_dir)
+        .arg("--check")
+        .output()
+        .expect("failed to run run-all");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "run-all failed:\n{stdout}\n{stderr}"
+    );
+    assert!(
+        stdout.lines().all(|line| line.ends_with(": PASS")),
+        "{stdout}"
+    );
+}
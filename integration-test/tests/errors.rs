@@ -0,0 +1,164 @@
+//! Malformed inputs are rejected with the location of the offending text, and inputs without an
+//! answer fail only the parts which can't be solved.
+
+use std::collections::BTreeSet;
+
+use common::{Answer, Error, Part};
+use pretty_assertions::assert_eq;
+
+fn parse_error(day_number: u32, input: &str) -> String {
+    let day = solvers::find(day_number).expect("day is solved");
+
+    match day.parse(input) {
+        Ok(_) => panic!("day {day_number} accepted {input:?}"),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn test_parse_errors_are_located() {
+    let cases = [
+        (
+            1,
+            "1000\n2000\n\n3O00\n",
+            r#"line 4, column 1: invalid number (invalid digit found in string): "3O00""#,
+        ),
+        (
+            2,
+            "A Y\nB X\nD Z\n",
+            r#"line 3, column 1: unknown opponent's move: "D""#,
+        ),
+        (
+            3,
+            "vJrwpWtwJgWr\nPmmdzq1rVv\n",
+            r#"line 2, column 7: unknown item type: "1""#,
+        ),
+        (
+            4,
+            "2-4,6-8\n2-3;4-5\n",
+            r#"line 2, column 1: no "," delimiter: "2-3;4-5""#,
+        ),
+        (
+            5,
+            "[A]\n 1 \n\nmove 1 from 1 to 1\nmove 1 from 1 to 2\n",
+            r#"line 5, column 1: movement of unknown stacks, there are 1: "move 1 from 1 to 2""#,
+        ),
+        (
+            5,
+            "[A]\n 1 \n\nmove 1 frm 1 to 1\n",
+            r#"line 4, column 8: expected "from ": "frm 1 to 1""#,
+        ),
+        (
+            7,
+            "$ cd /\n$ ls\n12x a\n",
+            r#"line 3, column 1: invalid number (invalid digit found in string): "12x""#,
+        ),
+        (
+            8,
+            "123\n4a6\n789\n",
+            r#"line 2, column 2: unknown tree height: "a""#,
+        ),
+        (
+            9,
+            "R 4\nU x\n",
+            r#"line 2, column 3: invalid number (invalid digit found in string): "x""#,
+        ),
+        (
+            10,
+            "noop\naddx 3\nadd 5\n",
+            r#"line 3, column 1: unknown instruction: "add 5""#,
+        ),
+        (
+            11,
+            "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old ^ 19\n",
+            r#"line 3, column 24: unknown operator: "^""#,
+        ),
+        (
+            11,
+            "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n",
+            r#"line 4, column 1: no line starting with "  Test: divisible by ""#,
+        ),
+    ];
+
+    for (day, input, expected) in cases {
+        assert_eq!(parse_error(day, input), expected, "day {day}");
+    }
+}
+
+#[test]
+fn test_unsolvable_parts_fail_alone() {
+    let day = solvers::find(1).expect("day 1 is solved");
+    let report = run_all::run_on(
+        day,
+        "1000\n2000\n\n3000\n",
+        &BTreeSet::from([1, 2]),
+        1,
+        false,
+    )
+    .expect("input is valid");
+
+    assert_eq!(report.parts[0].answer, Ok(Answer::Number(3000)));
+    assert_eq!(
+        report.parts[1].answer,
+        Err(Error::unsolvable("less than 3 elves"))
+    );
+    assert!(report.has_errors());
+}
+
+#[test]
+fn test_overflows_are_errors() {
+    let calories = [i64::MAX; 3]
+        .map(|calories| calories.to_string())
+        .join("\n\n");
+    let solution = solvers::find(1).unwrap().parse(&calories).unwrap();
+
+    assert_eq!(solution.try_solve(Part::One), Ok(Answer::Number(i64::MAX)));
+    assert_eq!(
+        solution.try_solve(Part::Two),
+        Err(Error::unsolvable("total calories of top 3 elves overflow"))
+    );
+
+    let program = format!("addx {}\naddx 1\n", i64::MAX - 1);
+    let solution = solvers::find(10).unwrap().parse(&program).unwrap();
+
+    assert_eq!(
+        solution.try_solve(Part::Two),
+        Err(Error::invalid_state("register overflows at cycle 4"))
+    );
+
+    let program = format!("addx {}\n{}", i64::MAX / 2, "noop\n".repeat(20));
+    let solution = solvers::find(10).unwrap().parse(&program).unwrap();
+
+    assert_eq!(
+        solution.try_solve(Part::One),
+        Err(Error::invalid_state(
+            "signal strength overflows at cycle 20"
+        ))
+    );
+
+    let log = format!("$ cd /\n$ ls\n{} a\n1 b\n", usize::MAX);
+    let solution = solvers::find(7).unwrap().parse(&log).unwrap();
+
+    assert_eq!(
+        solution.try_solve(Part::One),
+        Err(Error::invalid_state(
+            r#"total size overflows at entry "/b""#
+        ))
+    );
+
+    let monkey = |n, divisible_by, to| {
+        format!(
+            "Monkey {n}:\n  Starting items: 79\n  Operation: new = old * 19\n  \
+             Test: divisible by {divisible_by}\n    If true: throw to monkey {to}\n    \
+             If false: throw to monkey {to}\n"
+        )
+    };
+    // coprime, so their least common multiple is their product, over `u64::MAX`
+    let notes = [monkey(0, 1u64 << 32, 1), monkey(1, (1 << 32) + 1, 0)].join("\n");
+    let solution = solvers::find(11).unwrap().parse(&notes).unwrap();
+
+    assert_eq!(
+        solution.try_solve(Part::One),
+        Err(Error::unsolvable("monkey divisors overflow"))
+    );
+}
@@ -0,0 +1,34 @@
+//! Replays the inputs which once crashed the fuzz targets, so the parser fixes don't regress
+//! without nightly and `cargo fuzz`.
+//!
+//! Every day's artifacts are in `fuzz/artifacts/day_NN`, where `cargo fuzz run day_NN` puts them.
+
+use std::{fs, path::Path};
+
+#[test]
+fn test_fuzz_artifacts_parse_without_panics() {
+    let artifacts_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fuzz/artifacts");
+
+    let mut n_replayed = 0;
+    for day in solvers::DAYS {
+        let day_dir = artifacts_dir.join(format!("day_{:02}", day.number()));
+        let Ok(entries) = fs::read_dir(&day_dir) else {
+            continue;
+        };
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let data = fs::read(&path).unwrap();
+
+            // same as the fuzz target: any result is fine, as long as there is no panic
+            let _ = day.parse(&String::from_utf8_lossy(&data));
+            n_replayed += 1;
+        }
+    }
+
+    assert!(
+        n_replayed > 0,
+        "no artifacts in {}",
+        artifacts_dir.display()
+    );
+}
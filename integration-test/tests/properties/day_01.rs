@@ -0,0 +1,42 @@
+use day_01::Solution;
+use proptest::{collection::vec, prelude::*};
+
+use crate::harness::{lines, read_and_parse, solve_number};
+
+fn calories() -> impl Strategy<Value = Vec<Vec<i64>>> {
+    vec(vec(1..100_000i64, 1..10), 3..50)
+}
+
+fn render(elves: &[Vec<i64>]) -> String {
+    elves.iter().map(lines).collect::<Vec<_>>().join("\n")
+}
+
+proptest! {
+    #[test]
+    fn test_reference(elves in calories()) {
+        let mut totals: Vec<i64> = elves.iter().map(|elf| elf.iter().sum()).collect();
+        totals.sort_unstable();
+        totals.reverse();
+
+        let input = render(&elves);
+        prop_assert_eq!(solve_number(1, &input, 1), totals[0]);
+        prop_assert_eq!(solve_number(1, &input, 2), totals[..3].iter().sum::<i64>());
+    }
+
+    #[test]
+    fn test_top_three_bounded_by_top_one(elves in calories()) {
+        let input = render(&elves);
+        let (one, three) = (solve_number(1, &input, 1), solve_number(1, &input, 2));
+
+        prop_assert!(one <= three && three <= 3 * one);
+    }
+
+    #[test]
+    fn test_read(elves in calories()) {
+        let input = render(&elves);
+
+        for (read, parsed) in read_and_parse::<Solution>(1, &input) {
+            prop_assert_eq!(read, parsed);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use day_02::{Scores, Solution};
+use proptest::{collection::vec, prelude::*};
+
+use crate::harness::{lines, read_and_parse, solve_number};
+
+fn guide() -> impl Strategy<Value = Vec<(usize, usize)>> {
+    vec((0..3usize, 0..3usize), 1..100)
+}
+
+fn render(guide: &[(usize, usize)]) -> String {
+    lines(
+        guide
+            .iter()
+            .map(|&(left, right)| format!("{} {}", b"ABC"[left] as char, b"XYZ"[right] as char)),
+    )
+}
+
+/// Score of the round, with moves as `0` (rock), `1` (paper) and `2` (scissors).
+fn score(other: usize, mine: usize) -> i64 {
+    let outcome = match (other, mine) {
+        (a, b) if a == b => 3,
+        (0, 1) | (1, 2) | (2, 0) => 6,
+        _ => 0,
+    };
+
+    mine as i64 + 1 + outcome
+}
+
+proptest! {
+    #[test]
+    fn test_reference(guide in guide()) {
+        let input = render(&guide);
+
+        let as_moves = guide.iter().map(|&(other, mine)| score(other, mine)).sum::<i64>();
+        let as_outcomes = guide
+            .iter()
+            .map(|&(other, outcome)| {
+                let mine = (0..3).find(|&mine| score(other, mine) - mine as i64 - 1 == 3 * outcome as i64);
+                score(other, mine.unwrap())
+            })
+            .sum::<i64>();
+
+        prop_assert_eq!(solve_number(2, &input, 1), as_moves);
+        prop_assert_eq!(solve_number(2, &input, 2), as_outcomes);
+    }
+
+    #[test]
+    fn test_scores_are_bounded(guide in guide()) {
+        let input = render(&guide);
+        let n_rounds = guide.len() as i64;
+
+        for part in [1, 2] {
+            let score = solve_number(2, &input, part);
+            prop_assert!((n_rounds..=9 * n_rounds).contains(&score));
+        }
+    }
+
+    #[test]
+    fn test_round_trip(guide in guide()) {
+        let input = render(&guide);
+
+        prop_assert_eq!(input.parse::<Solution>().unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn test_read(guide in guide()) {
+        let input = render(&guide);
+
+        for (read, parsed) in read_and_parse::<Scores>(2, &input) {
+            prop_assert_eq!(read, parsed);
+        }
+    }
+}
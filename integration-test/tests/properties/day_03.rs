@@ -0,0 +1,36 @@
+use proptest::prelude::*;
+
+use crate::harness::{generated, solve_number};
+
+const ITEM_TYPES: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn priority(item: char) -> i64 {
+    ITEM_TYPES.find(item).unwrap() as i64 + 1
+}
+
+proptest! {
+    #[test]
+    fn test_reference(input in generated(3, 1..=30)) {
+        let rucksacks: Vec<&str> = input.lines().collect();
+
+        let misplaced: i64 = rucksacks
+            .iter()
+            .map(|rucksack| {
+                let (left, right) = rucksack.split_at(rucksack.len() / 2);
+                priority(left.chars().find(|&item| right.contains(item)).unwrap())
+            })
+            .sum();
+        let badges: i64 = rucksacks
+            .chunks(3)
+            .map(|group| {
+                let badge = ITEM_TYPES
+                    .chars()
+                    .find(|&item| group.iter().all(|rucksack| rucksack.contains(item)));
+                priority(badge.unwrap())
+            })
+            .sum();
+
+        prop_assert_eq!(solve_number(3, &input, 1), misplaced);
+        prop_assert_eq!(solve_number(3, &input, 2), badges);
+    }
+}
@@ -0,0 +1,62 @@
+use day_04::{Overlaps, Solution};
+use proptest::{collection::vec, prelude::*};
+
+use crate::harness::{lines, read_and_parse, solve_number};
+
+type Pair = ((u32, u32), (u32, u32));
+
+fn pairs() -> impl Strategy<Value = Vec<Pair>> {
+    let range = (1..100u32, 0..20u32).prop_map(|(start, len)| (start, start + len));
+
+    vec((range.clone(), range), 1..100)
+}
+
+fn render(pairs: &[Pair]) -> String {
+    lines(
+        pairs
+            .iter()
+            .map(|((a, b), (c, d))| format!("{a}-{b},{c}-{d}")),
+    )
+}
+
+proptest! {
+    #[test]
+    fn test_reference(pairs in pairs()) {
+        let input = render(&pairs);
+
+        let contained = pairs
+            .iter()
+            .filter(|((a, b), (c, d))| (a <= c && d <= b) || (c <= a && b <= d))
+            .count() as i64;
+        let overlapping = pairs
+            .iter()
+            .filter(|((a, b), (c, d))| a <= d && c <= b)
+            .count() as i64;
+
+        prop_assert_eq!(solve_number(4, &input, 1), contained);
+        prop_assert_eq!(solve_number(4, &input, 2), overlapping);
+    }
+
+    #[test]
+    fn test_overlaps_bound_containments(pairs in pairs()) {
+        let input = render(&pairs);
+
+        prop_assert!(solve_number(4, &input, 1) <= solve_number(4, &input, 2));
+    }
+
+    #[test]
+    fn test_round_trip(pairs in pairs()) {
+        let input = render(&pairs);
+
+        prop_assert_eq!(input.parse::<Solution>().unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn test_read(pairs in pairs()) {
+        let input = render(&pairs);
+
+        for (read, parsed) in read_and_parse::<Overlaps>(4, &input) {
+            prop_assert_eq!(read, parsed);
+        }
+    }
+}
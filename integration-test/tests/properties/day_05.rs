@@ -0,0 +1,90 @@
+use common::Answer;
+use proptest::prelude::*;
+
+use crate::harness::{generated, solve};
+
+/// Top crates, the text answer of both parts.
+fn top_crates(answer: Answer) -> String {
+    match answer {
+        Answer::Text(crates) => crates,
+        answer => panic!("answer `{answer}` is not text"),
+    }
+}
+
+/// Number of crates, stack to move from and stack to move to (both 0-based).
+type Move = (usize, usize, usize);
+
+/// Stacks (bottom to top) and moves of the input, parsed independently of the solution.
+fn parse(input: &str) -> (Vec<Vec<char>>, Vec<Move>) {
+    let (drawing, moves) = input.split_once("\n\n").unwrap();
+    let mut rows: Vec<&str> = drawing.lines().collect();
+    let n_stacks = rows.pop().unwrap().split_whitespace().count();
+
+    let mut stacks = vec![vec![]; n_stacks];
+    for row in rows.iter().rev() {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match row.chars().nth(4 * i + 1) {
+                Some(' ') | None => {}
+                Some(crate_) => stack.push(crate_),
+            }
+        }
+    }
+
+    let moves = moves
+        .lines()
+        .map(|line| {
+            let numbers: Vec<usize> = line
+                .split(' ')
+                .filter_map(|word| word.parse().ok())
+                .collect();
+            (numbers[0], numbers[1] - 1, numbers[2] - 1)
+        })
+        .collect();
+
+    (stacks, moves)
+}
+
+fn tops(stacks: &[Vec<char>]) -> String {
+    stacks
+        .iter()
+        .map(|stack| stack.last().copied().unwrap_or(' '))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn test_reference(input in generated(5, 1..=50)) {
+        let (stacks, moves) = parse(&input);
+
+        // one crate at a time
+        let mut one_by_one = stacks.clone();
+        for &(n, from, to) in &moves {
+            for _ in 0..n {
+                let crate_ = one_by_one[from].pop().unwrap();
+                one_by_one[to].push(crate_);
+            }
+        }
+
+        // all at once
+        let mut at_once = stacks;
+        for &(n, from, to) in &moves {
+            let at = at_once[from].len() - n;
+            let moved = at_once[from].split_off(at);
+            at_once[to].extend(moved);
+        }
+
+        prop_assert_eq!(top_crates(solve(5, &input, 1)), tops(&one_by_one));
+        prop_assert_eq!(top_crates(solve(5, &input, 2)), tops(&at_once));
+    }
+
+    #[test]
+    fn test_same_stacks_are_empty(input in generated(5, 1..=50)) {
+        let one = top_crates(solve(5, &input, 1));
+        let two = top_crates(solve(5, &input, 2));
+
+        prop_assert_eq!(one.len(), two.len());
+        for (a, b) in one.chars().zip(two.chars()) {
+            prop_assert_eq!(a == ' ', b == ' ');
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use day_06::Datastream;
+use proptest::prelude::*;
+
+use crate::harness::{read_and_parse, solve_number};
+
+/// Random letters, followed by a window of 14 different ones, so that both markers exist.
+fn datastream() -> impl Strategy<Value = String> {
+    let marker = Just(('a'..='z').collect::<Vec<_>>())
+        .prop_shuffle()
+        .prop_map(|letters| letters[..14].iter().collect::<String>());
+
+    ("[a-z]{0,200}", marker).prop_map(|(noise, marker)| noise + &marker)
+}
+
+fn marker_end(stream: &str, size: usize) -> i64 {
+    let bytes = stream.as_bytes();
+    let end = (size..=bytes.len())
+        .find(|&end| {
+            let window = &bytes[end - size..end];
+            (0..size).all(|i| (i + 1..size).all(|j| window[i] != window[j]))
+        })
+        .unwrap();
+
+    end as i64
+}
+
+proptest! {
+    #[test]
+    fn test_reference(stream in datastream()) {
+        prop_assert_eq!(solve_number(6, &stream, 1), marker_end(&stream, 4));
+        prop_assert_eq!(solve_number(6, &stream, 2), marker_end(&stream, 14));
+    }
+
+    #[test]
+    fn test_message_is_after_packet(stream in datastream()) {
+        // first 4 characters of the message marker are a packet marker
+        prop_assert!(solve_number(6, &stream, 1) + 10 <= solve_number(6, &stream, 2));
+    }
+
+    #[test]
+    fn test_read(stream in datastream()) {
+        for (read, parsed) in read_and_parse::<Datastream>(6, &stream) {
+            prop_assert_eq!(read, parsed);
+        }
+    }
+}
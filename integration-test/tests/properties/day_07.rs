@@ -0,0 +1,49 @@
+use proptest::prelude::*;
+
+use crate::harness::{generated, solve_number};
+
+/// Sizes of all directories, replaying the log with a stack of the ones being visited.
+fn dir_sizes(log: &str) -> Vec<i64> {
+    let mut visiting = vec![];
+    let mut sizes = vec![];
+    for line in log.lines() {
+        if line == "$ cd .." {
+            let size = visiting.pop().unwrap();
+            *visiting.last_mut().unwrap() += size;
+            sizes.push(size);
+        } else if line.starts_with("$ cd ") {
+            visiting.push(0);
+        } else if let Some(size) = line
+            .split(' ')
+            .next()
+            .and_then(|size| size.parse::<i64>().ok())
+        {
+            *visiting.last_mut().unwrap() += size;
+        }
+    }
+    while let Some(size) = visiting.pop() {
+        if let Some(parent) = visiting.last_mut() {
+            *parent += size;
+        }
+        sizes.push(size);
+    }
+
+    sizes
+}
+
+proptest! {
+    #[test]
+    fn test_reference(log in generated(7, 1..=60)) {
+        let sizes = dir_sizes(&log);
+        let small: i64 = sizes.iter().filter(|&&size| size <= 100_000).sum();
+        prop_assert_eq!(solve_number(7, &log, 1), small);
+
+        let root = *sizes.last().unwrap();
+        let need_to_free = root + 30_000_000 - 70_000_000;
+        let smallest = match need_to_free < 0 {
+            true => 0,
+            false => *sizes.iter().filter(|&&size| size >= need_to_free).min().unwrap(),
+        };
+        prop_assert_eq!(solve_number(7, &log, 2), smallest);
+    }
+}
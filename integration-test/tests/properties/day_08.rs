@@ -0,0 +1,94 @@
+use day_08::Solution;
+use proptest::{collection::vec, prelude::*};
+
+use crate::harness::{lines, solve_number};
+
+fn grid() -> impl Strategy<Value = Vec<Vec<u8>>> {
+    (1..15usize, 1..15usize).prop_flat_map(|(width, height)| vec(vec(0..10u8, width), height))
+}
+
+fn render(grid: &[Vec<u8>]) -> String {
+    lines(grid.iter().map(|row| {
+        row.iter()
+            .map(|&height| char::from(b'0' + height))
+            .collect::<String>()
+    }))
+}
+
+/// Visible trees in O(n²): a tree is visible if it's higher than the running maximum in some
+/// direction, which is computed once per row and column.
+fn count_visible(grid: &[Vec<u8>]) -> i64 {
+    let (width, height) = (grid[0].len(), grid.len());
+    let mut visible = vec![vec![false; width]; height];
+
+    let mut scan = |cells: &mut dyn Iterator<Item = (usize, usize)>| {
+        let mut highest = -1;
+        for (y, x) in cells {
+            if i16::from(grid[y][x]) > highest {
+                highest = grid[y][x].into();
+                visible[y][x] = true;
+            }
+        }
+    };
+    for y in 0..height {
+        scan(&mut (0..width).map(|x| (y, x)));
+        scan(&mut (0..width).rev().map(|x| (y, x)));
+    }
+    for x in 0..width {
+        scan(&mut (0..height).map(|y| (y, x)));
+        scan(&mut (0..height).rev().map(|y| (y, x)));
+    }
+
+    visible.iter().flatten().filter(|&&visible| visible).count() as i64
+}
+
+fn max_scenic_score(grid: &[Vec<u8>]) -> i64 {
+    let (n_cols, n_rows) = (grid[0].len() as isize, grid.len() as isize);
+    let mut best = 0;
+    for y in 0..n_rows {
+        for x in 0..n_cols {
+            let height = grid[y as usize][x as usize];
+            let mut score = 1;
+            for (dy, dx) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let (mut cy, mut cx, mut distance) = (y + dy, x + dx, 0);
+                while (0..n_rows).contains(&cy) && (0..n_cols).contains(&cx) {
+                    distance += 1;
+                    if grid[cy as usize][cx as usize] >= height {
+                        break;
+                    }
+                    (cy, cx) = (cy + dy, cx + dx);
+                }
+                score *= distance;
+            }
+            best = best.max(score);
+        }
+    }
+
+    best
+}
+
+proptest! {
+    #[test]
+    fn test_naive_equals_linear(grid in grid()) {
+        let input = render(&grid);
+
+        prop_assert_eq!(solve_number(8, &input, 1), count_visible(&grid));
+        prop_assert_eq!(solve_number(8, &input, 2), max_scenic_score(&grid));
+    }
+
+    #[test]
+    fn test_edge_is_visible(grid in grid()) {
+        let (width, height) = (grid[0].len() as i64, grid.len() as i64);
+        let edge = if width == 1 || height == 1 { width * height } else { 2 * (width + height) - 4 };
+
+        let visible = solve_number(8, &render(&grid), 1);
+        prop_assert!((edge..=width * height).contains(&visible));
+    }
+
+    #[test]
+    fn test_round_trip(grid in grid()) {
+        let input = render(&grid);
+
+        prop_assert_eq!(input.parse::<Solution>().unwrap().to_string(), input);
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use day_09::{Solution, Tails};
+use proptest::{collection::vec, prelude::*};
+
+use crate::harness::{lines, read_and_parse, solve_number};
+
+fn moves() -> impl Strategy<Value = Vec<(char, i64)>> {
+    vec(
+        (prop::sample::select(vec!['L', 'R', 'U', 'D']), 1..10i64),
+        1..100,
+    )
+}
+
+fn render(moves: &[(char, i64)]) -> String {
+    lines(
+        moves
+            .iter()
+            .map(|(direction, n)| format!("{direction} {n}")),
+    )
+}
+
+/// Positions visited by the tail of a rope with `n_knots`, moving the head one step at a time.
+fn visited(moves: &[(char, i64)], n_knots: usize) -> i64 {
+    let mut knots = vec![(0i64, 0i64); n_knots];
+    let mut visited = HashSet::from([(0, 0)]);
+
+    for &(direction, n) in moves {
+        let (dx, dy) = match direction {
+            'L' => (-1, 0),
+            'R' => (1, 0),
+            'U' => (0, -1),
+            _ => (0, 1),
+        };
+        for _ in 0..n {
+            knots[0] = (knots[0].0 + dx, knots[0].1 + dy);
+            for i in 1..n_knots {
+                let (head, tail) = (knots[i - 1], knots[i]);
+                if (head.0 - tail.0).abs() > 1 || (head.1 - tail.1).abs() > 1 {
+                    knots[i] = (
+                        tail.0 + (head.0 - tail.0).signum(),
+                        tail.1 + (head.1 - tail.1).signum(),
+                    );
+                }
+            }
+            visited.insert(knots[n_knots - 1]);
+        }
+    }
+
+    visited.len() as i64
+}
+
+proptest! {
+    #[test]
+    fn test_reference(moves in moves()) {
+        prop_assert_eq!(solve_number(9, &render(&moves), 1), visited(&moves, 2));
+        prop_assert_eq!(solve_number(9, &render(&moves), 2), visited(&moves, 10));
+    }
+
+    #[test]
+    fn test_tail_is_slower_than_head(moves in moves()) {
+        let n_steps: i64 = moves.iter().map(|(_, n)| n).sum();
+
+        prop_assert!(solve_number(9, &render(&moves), 1) <= n_steps + 1);
+        // the longer rope follows the shorter one
+        prop_assert!(solve_number(9, &render(&moves), 2) <= solve_number(9, &render(&moves), 1));
+    }
+
+    #[test]
+    fn test_round_trip(moves in moves()) {
+        let input = render(&moves);
+
+        prop_assert_eq!(input.parse::<Solution>().unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn test_read(moves in moves()) {
+        let input = render(&moves);
+
+        for (read, parsed) in read_and_parse::<Tails>(9, &input) {
+            prop_assert_eq!(read, parsed);
+        }
+    }
+}
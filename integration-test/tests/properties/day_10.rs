@@ -0,0 +1,72 @@
+use common::Answer;
+use day_10::{Program, Solution};
+use proptest::{collection::vec, prelude::*};
+
+use crate::harness::{lines, read_and_parse, solve, solve_number};
+
+/// `None` is `noop`. There are always enough instructions for 240 cycles.
+fn program() -> impl Strategy<Value = Vec<Option<i64>>> {
+    vec(prop::option::weighted(0.7, -20..=20i64), 240..300)
+}
+
+fn render(program: &[Option<i64>]) -> String {
+    lines(program.iter().map(|instruction| match instruction {
+        Some(x) => format!("addx {x}"),
+        None => "noop".to_owned(),
+    }))
+}
+
+/// Value of `X` during every cycle, starting from the first one.
+fn x_values(program: &[Option<i64>]) -> Vec<i64> {
+    let mut x = 1;
+    let mut values = vec![];
+    for instruction in program {
+        match instruction {
+            Some(add) => {
+                values.extend([x, x]);
+                x += add;
+            }
+            None => values.push(x),
+        }
+    }
+
+    values
+}
+
+proptest! {
+    #[test]
+    fn test_reference(program in program()) {
+        let input = render(&program);
+        let xs = x_values(&program);
+
+        let strength: i64 = (20..=220).step_by(40).map(|cycle| cycle as i64 * xs[cycle - 1]).sum();
+        prop_assert_eq!(solve_number(10, &input, 1), strength);
+
+        let screen = xs[..240]
+            .chunks(40)
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(i, x)| if (i as i64).abs_diff(*x) <= 1 { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        prop_assert_eq!(solve(10, &input, 2), Answer::Grid(screen));
+    }
+
+    #[test]
+    fn test_round_trip(program in program()) {
+        let input = render(&program);
+
+        prop_assert_eq!(input.parse::<Solution>().unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn test_read(program in program()) {
+        let input = render(&program);
+
+        for (read, parsed) in read_and_parse::<Program>(10, &input) {
+            prop_assert_eq!(read, parsed);
+        }
+    }
+}
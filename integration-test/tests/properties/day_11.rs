@@ -0,0 +1,28 @@
+use proptest::prelude::*;
+
+use crate::harness::{generated, solve_number};
+
+/// Number of items in the notes. A monkey inspects each of them at most once per round, though
+/// an item thrown to a later monkey is inspected again in the same round.
+fn n_items(notes: &str) -> i64 {
+    notes
+        .lines()
+        .filter_map(|line| line.strip_prefix("  Starting items: "))
+        .map(|items| items.split(", ").count() as i64)
+        .sum()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn test_business_is_bounded(notes in generated(11, 2..=6)) {
+        let n_items = n_items(&notes);
+
+        for (part, n_rounds) in [(1, 20), (2, 10_000)] {
+            // of a single monkey
+            let max_inspections = n_items * n_rounds;
+            prop_assert!(solve_number(11, &notes, part) <= max_inspections * max_inspections);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Shared pieces of the per-day properties.
+
+use std::{fmt::Display, ops::RangeInclusive};
+
+use common::{Answer, FromReader, TrySolve};
+use proptest::prelude::*;
+
+/// Parses `input` with day's `Solution` and solves the part, panicking with the input on errors.
+pub fn solve(day: u32, input: &str, part: u8) -> Answer {
+    let solver = solvers::find(day)
+        .expect("day is solved")
+        .parse(input)
+        .unwrap_or_else(|err| panic!("failed to parse input of day {day}: {err}\n{input}"));
+
+    solver
+        .try_solve(solvers::part(part).expect("part is valid"))
+        .unwrap_or_else(|err| panic!("failed to solve part {part} of day {day}: {err}\n{input}"))
+}
+
+/// Answers of both parts from `T` read line by line, and from the day's `Solution` parsed from the
+/// whole `input`, which should be the same.
+pub fn read_and_parse<T: FromReader + TrySolve>(day: u32, input: &str) -> [(Answer, Answer); 2] {
+    let read = T::from_reader(input.as_bytes())
+        .unwrap_or_else(|err| panic!("failed to read input of day {day}: {err}\n{input}"));
+
+    [1, 2].map(|part| {
+        let answer = read
+            .try_solve(solvers::part(part).expect("part is valid"))
+            .unwrap_or_else(|err| panic!("failed to solve read part {part} of day {day}: {err}"));
+
+        (answer, solve(day, input, part))
+    })
+}
+
+/// Solves the part, expecting a number as the answer.
+pub fn solve_number(day: u32, input: &str, part: u8) -> i64 {
+    match solve(day, input, part) {
+        Answer::Number(n) => n,
+        answer => panic!("answer `{answer}` is not a number"),
+    }
+}
+
+/// Inputs from day's generator in the `generators` crate, with sizes from the range.
+pub fn generated(day: u32, sizes: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    let generator = generators::find(day).expect("day has a generator");
+
+    (sizes, any::<u64>()).prop_map(|(size, seed)| generator.generate(size, seed))
+}
+
+/// Every item on its own line, each line ending with `\n`.
+pub fn lines<T: Display>(items: impl IntoIterator<Item = T>) -> String {
+    items.into_iter().map(|item| format!("{item}\n")).collect()
+}
@@ -0,0 +1,19 @@
+//! Property-based tests of all days: every day has a strategy generating valid inputs and checks
+//! answers against naive reference implementations, bounds between the parts and, for days which
+//! can write their input back, `parse → to_string` round trips.
+//!
+//! Failing cases are shrunk by proptest and stored in `proptest-regressions/` next to this file.
+
+mod harness;
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
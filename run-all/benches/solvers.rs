@@ -0,0 +1,42 @@
+//! Parsing and every part of all days on their real inputs.
+//!
+//! Criterion keeps results of the previous run in `target/criterion` and reports significant
+//! changes against it. Use `-- --save-baseline NAME` and `-- --baseline NAME` to compare with a
+//! named one instead, and a filter such as `-- day-07` to run only some of the benchmarks.
+
+use std::{fs, hint::black_box, path::Path};
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+fn solvers(c: &mut Criterion) {
+    let base_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    for day in solvers::DAYS {
+        let path = base_path.join(format!("day-{:02}/input.txt", day.number()));
+        let Ok(input) = fs::read_to_string(&path) else {
+            eprintln!("skipping day {:02}: no `{}`", day.number(), path.display());
+            continue;
+        };
+
+        let mut group = c.benchmark_group(format!("day-{:02}", day.number()));
+        group.bench_function("parse", |b| {
+            b.iter(|| day.parse(black_box(&input)).expect("input is valid"))
+        });
+
+        let solver = day.parse(&input).expect("input is valid");
+        for part_number in day.part_numbers() {
+            group.bench_function(format!("part-{part_number}"), |b| {
+                b.iter_batched(
+                    || solvers::part(part_number).expect("implemented part is valid"),
+                    |part| solver.try_solve(part).expect("input is valid"),
+                    BatchSize::SmallInput,
+                )
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solvers);
+criterion_main!(benches);
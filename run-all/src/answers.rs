@@ -0,0 +1,180 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Write},
+    fs, io,
+    path::Path,
+};
+
+use common::Answer;
+use serde::Serialize;
+use toml::Value;
+
+/// Expected answers, stored as a TOML file, numbers as integers and everything else as strings:
+///
+/// ```toml
+/// [day-05]
+/// part-1 = "CFFHVVHNC"
+///
+/// [day-07]
+/// part-1 = 1297159
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Value>>);
+
+/// Answer compared with the expected one, `PASS`, `FAIL` or `NEW` (there is no expected answer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Verdict {
+    Pass,
+    Fail,
+    New,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::New => "NEW",
+        };
+
+        write!(f, "{status}")
+    }
+}
+
+impl Answers {
+    /// Missing file is the same as the empty one.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let data = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(format!("failed to read `{}`: {err}", path.display()).into()),
+        };
+
+        toml::from_str(&data)
+            .map(Self)
+            .map_err(|err| format!("invalid answers file `{}`: {err}", path.display()).into())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string_pretty(&self.0)?)
+            .map_err(|err| format!("failed to write `{}`: {err}", path.display()).into())
+    }
+
+    /// Expected answer, rendered the same way as `common::Answer`.
+    pub fn get(&self, day: u32, part: u8) -> Option<String> {
+        match self.0.get(&day_key(day))?.get(&part_key(part))? {
+            Value::String(answer) => Some(answer.clone()),
+            answer => Some(answer.to_string()),
+        }
+    }
+
+    /// Records `answer`, numbers as integers and texts and grids rendered as strings.
+    pub fn set(&mut self, day: u32, part: u8, answer: &Answer) {
+        let value = match answer {
+            Answer::Number(number) => Value::Integer(*number),
+            answer => Value::String(answer.to_string()),
+        };
+
+        self.0
+            .entry(day_key(day))
+            .or_default()
+            .insert(part_key(part), value);
+    }
+
+    pub fn check(&self, day: u32, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::New,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+        }
+    }
+}
+
+fn day_key(day: u32) -> String {
+    format!("day-{day:02}")
+}
+
+fn part_key(part: u8) -> String {
+    format!("part-{part}")
+}
+
+/// Line by line diff, good enough for the answers (which are mostly single-line).
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+
+    let mut result = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                writeln!(result, "    {expected}").unwrap()
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    writeln!(result, "  - {expected}").unwrap();
+                }
+                if let Some(actual) = actual {
+                    writeln!(result, "  + {actual}").unwrap();
+                }
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.set(7, 1, &Answer::Number(95437));
+        answers.set(5, 1, &Answer::Text("CMZ".to_owned()));
+
+        assert_eq!(answers.check(7, 1, "95437"), Verdict::Pass);
+        assert_eq!(answers.check(7, 1, "0"), Verdict::Fail);
+        assert_eq!(answers.check(5, 1, "CMZ"), Verdict::Pass);
+        assert_eq!(answers.check(7, 2, "whatever"), Verdict::New);
+        assert_eq!(answers.check(8, 1, "whatever"), Verdict::New);
+
+        assert_eq!(Verdict::Fail.to_string(), "FAIL");
+        assert_eq!(serde_json::to_string(&Verdict::New).unwrap(), r#""NEW""#);
+    }
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(
+            10,
+            2,
+            &Answer::Grid(vec!["#..#".to_owned(), ".##.".to_owned()]),
+        );
+        answers.set(1, 1, &Answer::Number(42));
+        // looks like a number, but isn't one
+        answers.set(5, 1, &Answer::Text("007".to_owned()));
+
+        let serialized = toml::to_string_pretty(&answers.0).unwrap();
+        assert!(
+            serialized.starts_with("[day-01]\npart-1 = 42\n"),
+            "{serialized}"
+        );
+        assert!(serialized.contains("part-1 = '007'"), "{serialized}");
+
+        let answers = Answers(toml::from_str(&serialized).unwrap());
+        assert_eq!(answers.check(1, 1, "42"), Verdict::Pass);
+        assert_eq!(answers.check(5, 1, "007"), Verdict::Pass);
+        assert_eq!(answers.check(10, 2, "#..#\n.##.\n"), Verdict::Pass);
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff("a\nb\nc", "a\nx\nc\nd"),
+            "    a\n  - b\n  + x\n    c\n  + d\n"
+        );
+    }
+}
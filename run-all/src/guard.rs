@@ -0,0 +1,85 @@
+use std::{
+    cell::{Cell, RefCell},
+    error::Error,
+    panic::{self, AssertUnwindSafe},
+};
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the current panic hook into one remembering panic message and location inside
+/// [`catch`], so that it could report them as an ordinary error. Panics outside of it are passed
+/// to the previous hook, i.e. printed to stderr by default.
+pub fn install_panic_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !CATCHING.with(Cell::get) {
+            return previous(info);
+        }
+
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("Box<dyn Any>");
+        let message = match info.location() {
+            Some(location) => format!("panicked at {location}: {message}"),
+            None => format!("panicked: {message}"),
+        };
+
+        LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+    }));
+}
+
+/// Runs `f`, turning both returned errors and panics into error messages.
+pub fn catch<T>(f: impl FnOnce() -> Result<T, Box<dyn Error>>) -> Result<T, String> {
+    let outer = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(outer));
+
+    match result {
+        Ok(result) => result.map_err(|err| err.to_string()),
+        Err(_) => Err(LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| "panicked".to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Once;
+
+    /// The hook is process-wide, while tests run in parallel.
+    fn install_once() {
+        static INSTALL: Once = Once::new();
+
+        INSTALL.call_once(install_panic_hook);
+    }
+
+    #[test]
+    fn test_catch() {
+        install_once();
+
+        assert_eq!(catch(|| Ok(42)), Ok(42));
+        assert_eq!(catch::<()>(|| Err("oops".into())), Err("oops".to_owned()));
+
+        let err = catch::<()>(|| panic!("no suitable dir to delete found :(")).unwrap_err();
+        assert!(
+            err.starts_with("panicked at run-all/src/guard.rs:"),
+            "{err}"
+        );
+        assert!(
+            err.ends_with(": no suitable dir to delete found :("),
+            "{err}"
+        );
+
+        // not caught by `catch`, so left to the previous hook
+        assert!(panic::catch_unwind(|| panic!("elsewhere")).is_err());
+        assert_eq!(LAST_PANIC.with(|last| last.borrow_mut().take()), None);
+    }
+}
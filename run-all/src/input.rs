@@ -0,0 +1,195 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Where to read input for a day from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "`{}`", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// `--input DAY=PATH` argument, `-` as the path means stdin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    pub day: u32,
+    pub source: Source,
+}
+
+impl FromStr for Override {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (day, path) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected `DAY=PATH`, got `{s}`"))?;
+        let day = day
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid day `{day}` in `{s}`"))?;
+        let source = match path {
+            "" => return Err(format!("empty path in `{s}`")),
+            "-" => Source::Stdin,
+            path => Source::File(path.into()),
+        };
+
+        Ok(Self { day, source })
+    }
+}
+
+/// Layout of the input files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Layout {
+    /// `<base>/day-07/input.txt`, i.e. next to the day's crate
+    Crates(PathBuf),
+    /// `<dir>/day07.txt`, e.g. `inputs/<user>/day07.txt`
+    Flat(PathBuf),
+}
+
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    layout: Layout,
+    overrides: BTreeMap<u32, Source>,
+}
+
+impl Inputs {
+    /// Fails if several days are going to be read from stdin.
+    pub fn new(layout: Layout, overrides: Vec<Override>) -> Result<Self, String> {
+        let mut stdin_day = None;
+        let mut by_day = BTreeMap::new();
+        for Override { day, source } in overrides {
+            if source == Source::Stdin {
+                if let Some(other) = stdin_day.replace(day) {
+                    return Err(format!(
+                        "only one day can read input from stdin, got days {other} and {day}"
+                    ));
+                }
+            }
+
+            if let Some(previous) = by_day.insert(day, source) {
+                return Err(format!(
+                    "several inputs are given for day {day}, including {previous}"
+                ));
+            }
+        }
+
+        Ok(Self {
+            layout,
+            overrides: by_day,
+        })
+    }
+
+    /// Days with the input overridden.
+    pub fn overridden_days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.overrides.keys().copied()
+    }
+
+    pub fn source(&self, day: u32) -> Source {
+        if let Some(source) = self.overrides.get(&day) {
+            return source.clone();
+        }
+
+        match &self.layout {
+            Layout::Crates(base) => Source::File(base.join(format!("day-{day:02}/input.txt"))),
+            Layout::Flat(dir) => Source::File(dir.join(format!("day{day:02}.txt"))),
+        }
+    }
+
+    pub fn read(&self, day: u32) -> Result<String, Box<dyn Error>> {
+        let source = self.source(day);
+        let data = match &source {
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => io::read_to_string(io::stdin()),
+        };
+
+        data.map_err(|err| format!("failed to read input from {source}: {err}").into())
+    }
+
+    /// Opens day's input to be read piece by piece rather than as a whole.
+    pub fn open(&self, day: u32) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+        let source = self.source(day);
+        let reader: io::Result<Box<dyn BufRead>> = match &source {
+            Source::File(path) => File::open(path).map(|file| Box::new(BufReader::new(file)) as _),
+            Source::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+        };
+
+        reader.map_err(|err| format!("failed to read input from {source}: {err}").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sources() {
+        let inputs = Inputs::new(
+            Layout::Flat("inputs/vlad".into()),
+            vec!["7=my/day7.txt".parse().unwrap(), "9=-".parse().unwrap()],
+        )
+        .unwrap();
+
+        assert_eq!(
+            inputs.source(1),
+            Source::File("inputs/vlad/day01.txt".into())
+        );
+        assert_eq!(inputs.source(7), Source::File("my/day7.txt".into()));
+        assert_eq!(inputs.source(9), Source::Stdin);
+
+        let inputs = Inputs::new(Layout::Crates("base".into()), vec![]).unwrap();
+        assert_eq!(
+            inputs.source(11),
+            Source::File("base/day-11/input.txt".into())
+        );
+    }
+
+    #[test]
+    fn test_invalid_overrides() {
+        assert!("7".parse::<Override>().is_err());
+        assert!("7=".parse::<Override>().is_err());
+        assert!("x=input.txt".parse::<Override>().is_err());
+
+        assert!(Inputs::new(
+            Layout::Crates(".".into()),
+            vec!["7=-".parse().unwrap(), "8=-".parse().unwrap()]
+        )
+        .is_err());
+        assert!(Inputs::new(
+            Layout::Crates(".".into()),
+            vec!["7=a.txt".parse().unwrap(), "7=b.txt".parse().unwrap()]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let inputs = Inputs::new(Layout::Flat("no/such/dir".into()), vec![]).unwrap();
+        let err = inputs.read(3).unwrap_err().to_string();
+
+        assert!(
+            err.starts_with("failed to read input from `no/such/dir/day03.txt`: "),
+            "{err}"
+        );
+
+        let err = inputs.open(3).err().unwrap().to_string();
+        assert!(
+            err.starts_with("failed to read input from `no/such/dir/day03.txt`: "),
+            "{err}"
+        );
+    }
+}
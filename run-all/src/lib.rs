@@ -0,0 +1,136 @@
+//! Running days and measuring them, shared by the `run-all` binary and the integration tests.
+
+pub mod answers;
+pub mod guard;
+pub mod input;
+pub mod memory;
+pub mod output;
+pub mod report;
+pub mod timing;
+pub mod trace;
+
+use std::{collections::BTreeSet, error::Error, io::BufRead, time::Instant};
+
+use common::Answer;
+use solvers::{Day, Solve};
+
+use input::{Inputs, Source};
+use report::{DayReport, PartReport};
+
+/// Reads day's input and runs the day on it, see [`run_with`].
+///
+/// Days which have a reader read the file or stdin through it on every run, the rest read the
+/// whole input once and parse it.
+pub fn run(
+    day: &Day,
+    inputs: &Inputs,
+    parts: &BTreeSet<u8>,
+    runs: u32,
+    count_allocations: bool,
+) -> Result<DayReport, Box<dyn Error>> {
+    if !day.has_reader() {
+        let input_data = inputs.read(day.number())?;
+
+        return run_on(day, &input_data, parts, runs, count_allocations);
+    }
+
+    if runs > 1 && inputs.source(day.number()) == Source::Stdin {
+        return Err(format!(
+            "input of day {} can't be read from stdin several times",
+            day.number()
+        )
+        .into());
+    }
+
+    let load = || read(day, &mut inputs.open(day.number())?);
+    run_with(day, load, parts, runs, count_allocations)
+}
+
+/// Parses `input_data` and runs the day on it, see [`run_with`].
+pub fn run_on(
+    day: &Day,
+    input_data: &str,
+    parts: &BTreeSet<u8>,
+    runs: u32,
+    count_allocations: bool,
+) -> Result<DayReport, Box<dyn Error>> {
+    let load = || Ok(day.parse(input_data)?);
+    run_with(day, load, parts, runs, count_allocations)
+}
+
+/// Reads the day piece by piece from `reader`.
+pub fn read(day: &Day, reader: &mut dyn BufRead) -> Result<Box<dyn Solve>, Box<dyn Error>> {
+    match day.from_reader(reader) {
+        Some(solver) => Ok(solver?),
+        None => Err(format!("day {} can't be read piece by piece", day.number()).into()),
+    }
+}
+
+/// Loads (reads or parses) the input and solves selected `parts`, doing each of those `runs`
+/// times.
+///
+/// Failure to load fails the whole day, while failure to solve a part is in its report.
+///
+/// With `count_allocations`, allocations are counted as well (on the last run).
+pub fn run_with(
+    day: &Day,
+    mut load: impl FnMut() -> Result<Box<dyn Solve>, Box<dyn Error>>,
+    parts: &BTreeSet<u8>,
+    runs: u32,
+    count_allocations: bool,
+) -> Result<DayReport, Box<dyn Error>> {
+    let mut parse_samples = Vec::with_capacity(runs as usize);
+    let mut solver = None;
+    let mut parse_memory = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let (loaded, allocations) = memory::measure(count_allocations, &mut load);
+        parse_samples.push(start.elapsed());
+
+        solver = Some(loaded?);
+        parse_memory = allocations;
+    }
+    let solver = solver.expect("at least one run is required");
+
+    let mut report = DayReport {
+        day: day.number(),
+        parse_samples,
+        parse_memory,
+        parts: vec![],
+    };
+    for part_number in day.part_numbers() {
+        if !parts.contains(&part_number) {
+            continue;
+        }
+
+        let part = solvers::part(part_number).expect("implemented part is valid");
+        let mut answer = Ok(Answer::Number(0));
+        let mut samples = Vec::with_capacity(runs as usize);
+        let mut allocations = None;
+        for _ in 0..runs {
+            let start = Instant::now();
+            (answer, allocations) = memory::measure(count_allocations, || solver.try_solve(part));
+            samples.push(start.elapsed());
+
+            // failures are not timed
+            if answer.is_err() {
+                break;
+            }
+        }
+
+        let description = answer
+            .as_ref()
+            .ok()
+            .map(|answer| solver.describe(&part, answer));
+
+        report.parts.push(PartReport {
+            part: part_number,
+            answer,
+            description,
+            samples,
+            memory: allocations,
+        });
+    }
+
+    Ok(report)
+}
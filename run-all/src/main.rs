@@ -1,35 +1,297 @@
-use std::{env::var, error::Error, str::FromStr};
+mod pool;
+mod scale;
+mod selection;
+mod watch;
 
-use advent_utils::{read_file, Solver};
+use std::{
+    collections::BTreeMap, env::var, error::Error, path::PathBuf, process::ExitCode, thread,
+};
 
-fn run<S: Solver + FromStr<Err = Box<dyn Error>>>() -> Result<(), Box<dyn Error>> {
-    let input_file = format!(
-        "{}/day-{:02}/input.txt",
-        var("BASE_PATH").unwrap_or_else(|_| ".".to_owned()),
-        S::day_number()
-    );
-    let input_data = read_file(input_file)?;
-    let solver: S = input_data.parse()?;
+use clap::{Parser, Subcommand};
+use common::Answer;
+use run_all::{
+    answers::{Answers, Verdict},
+    guard,
+    input::{Inputs, Layout, Override},
+    memory,
+    output::{Format, Output, Record},
+    run, timing, trace,
+};
 
-    for part in S::implemented_parts() {
-        println!("day {:02}: {}", S::day_number(), solver.solve(part));
+use selection::Selector;
+
+/// Runs the Advent of Code 2022 solutions
+#[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    args: Args,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Rebuild and re-run the day with its tests every time its sources or inputs change
+    Watch(watch::WatchArgs),
+    /// Time the day on generated inputs of growing sizes, estimating how solving time grows
+    Scale(scale::ScaleArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct Args {
+    /// Days and parts to run, e.g. `7`, `9:2`, `3-5` or `3-5:1`. Runs everything if omitted
+    selectors: Vec<Selector>,
+
+    /// How results are printed
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Measure parsing and solving of every part, printing a timings table in the end
+    #[arg(long)]
+    time: bool,
+
+    /// How many times to repeat every measurement with `--time`
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Read inputs from `DIR/dayNN.txt` instead of `$BASE_PATH/day-NN/input.txt`
+    #[arg(long, value_name = "DIR", conflicts_with = "user")]
+    input_dir: Option<PathBuf>,
+
+    /// Read inputs from `$BASE_PATH/inputs/USER/dayNN.txt`
+    #[arg(long)]
+    user: Option<String>,
+
+    /// Read input of a day from the file, e.g. `7=day7.txt`, or from stdin with `7=-`
+    #[arg(long = "input", value_name = "DAY=PATH")]
+    inputs: Vec<Override>,
+
+    /// Count allocations of parsing and every part, printing a table in the end. Requires
+    /// `alloc-stats` feature
+    #[arg(long)]
+    memory: bool,
+
+    /// Compare answers with the ones stored in the answers file
+    #[arg(long)]
+    check: bool,
+
+    /// With `--check`, store the current answers as the expected ones
+    #[arg(long, requires = "check")]
+    bless: bool,
+
+    /// Answers file, `answers.toml` in `$BASE_PATH` or the inputs directory by default
+    #[arg(long, value_name = "PATH")]
+    answers: Option<PathBuf>,
+
+    /// Print step events of the days to stderr, e.g. the rope after every move of day 9
+    #[arg(long)]
+    trace: bool,
+
+    /// With `--trace`, print only the events of the step, e.g. `cycle`. Can be repeated
+    #[arg(long = "trace-step", value_name = "STEP", requires = "trace")]
+    trace_steps: Vec<String>,
+
+    /// How many days to run concurrently, 0 to use all available cores. Output order is kept.
+    /// Can't be combined with `--time`, which would measure days competing for the cores
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+}
+
+/// Compares record's `answer` with the expected one, recording it with `bless` if it differs.
+fn check(
+    record: &mut Record,
+    answer: &Answer,
+    answers: &mut Answers,
+    bless: bool,
+    n_blessed: &mut usize,
+) -> Option<Verdict> {
+    let part = record.part?;
+
+    let verdict = answers.check(record.day, part, &answer.to_string());
+    record.status = Some(verdict);
+    if verdict == Verdict::Fail {
+        record.expected = answers.get(record.day, part);
+    }
+
+    if bless && verdict != Verdict::Pass {
+        answers.set(record.day, part, answer);
+        *n_blessed += 1;
     }
 
-    Ok(())
+    Some(verdict)
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    run::<day_01::Solution>()?;
-    run::<day_02::Solution>()?;
-    run::<day_03::Solution>()?;
-    run::<day_04::Solution>()?;
-    run::<day_05::Solution>()?;
-    run::<day_06::Solution>()?;
-    run::<day_07::Solution>()?;
-    run::<day_08::Solution>()?;
-    run::<day_09::Solution>()?;
-    run::<day_10::Solution>()?;
-    run::<day_11::Solution>()?;
-
-    Ok(())
+fn main() -> Result<ExitCode, Box<dyn Error>> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Command::Watch(args)) => watch::watch(args),
+        Some(Command::Scale(args)) => scale::scale(args),
+        None => run_all(cli.args),
+    }
+}
+
+fn run_all(args: Args) -> Result<ExitCode, Box<dyn Error>> {
+    let available = solvers::DAYS
+        .iter()
+        .map(|day| (day.number(), day.part_numbers()))
+        .collect();
+
+    let selected = selection::resolve(&args.selectors, &available)?;
+    if args.memory && !memory::ENABLED {
+        return Err("`--memory` requires run-all to be built with `alloc-stats` feature".into());
+    }
+    if args.time && args.jobs != 1 {
+        return Err("`--time` runs days one at a time, it can't be combined with `--jobs`".into());
+    }
+
+    let base_path = PathBuf::from(var("BASE_PATH").unwrap_or_else(|_| ".".to_owned()));
+    let layout = match (args.input_dir.clone(), args.user.clone()) {
+        (Some(dir), _) => Layout::Flat(dir),
+        (None, Some(user)) => Layout::Flat(base_path.join("inputs").join(user)),
+        (None, None) => Layout::Crates(base_path),
+    };
+    let answers_path = args.answers.clone().unwrap_or_else(|| match &layout {
+        Layout::Crates(base) => base.join("answers.toml"),
+        Layout::Flat(dir) => dir.join("answers.toml"),
+    });
+    let mut answers = if args.check {
+        Answers::load(&answers_path)?
+    } else {
+        Answers::default()
+    };
+    let inputs = Inputs::new(layout, args.inputs.clone())?;
+    if let Some(day) = inputs
+        .overridden_days()
+        .find(|day| !selected.contains_key(day))
+    {
+        return Err(format!("input is given for day {day}, which is not selected").into());
+    }
+    let runs = if args.time { args.runs } else { 1 };
+
+    let jobs = match args.jobs {
+        0 => thread::available_parallelism().map_or(1, usize::from),
+        jobs => jobs,
+    };
+    let to_run: Vec<_> = solvers::DAYS
+        .iter()
+        .filter_map(|day| Some((day, selected.get(&day.number())?)))
+        .collect();
+
+    guard::install_panic_hook();
+
+    let mut output = Output::new(args.format);
+    let mut timings = vec![];
+    let mut usages = vec![];
+    let mut failed = vec![];
+    let mut n_passed = 0;
+    let mut verdicts = BTreeMap::<_, usize>::new();
+    let mut n_blessed = 0;
+    pool::run_ordered(
+        &to_run,
+        jobs,
+        |&(day, parts)| {
+            (
+                day.number(),
+                guard::catch(|| {
+                    let run_day = || run(day, &inputs, parts, runs, args.memory);
+                    match args.trace {
+                        true => trace::traced(day.number(), &args.trace_steps, run_day),
+                        false => run_day(),
+                    }
+                }),
+            )
+        },
+        |(day, result)| {
+            match result {
+                Ok(report) => {
+                    // records are the parts in order
+                    for (mut record, part) in report.records().zip(&report.parts) {
+                        if let (true, Ok(answer)) = (args.check, &part.answer) {
+                            let verdict = check(
+                                &mut record,
+                                answer,
+                                &mut answers,
+                                args.bless,
+                                &mut n_blessed,
+                            );
+                            if let Some(verdict) = verdict {
+                                *verdicts.entry(verdict).or_default() += 1;
+                            }
+                        }
+                        output.emit(&record)?;
+                    }
+                    timings.extend(report.timings());
+                    usages.extend(report.memory());
+                    if report.has_errors() {
+                        failed.push(day);
+                    } else {
+                        n_passed += 1;
+                    }
+                }
+                Err(err) => {
+                    output.emit(&Record {
+                        day,
+                        error: Some(err),
+                        ..Record::default()
+                    })?;
+                    failed.push(day);
+                }
+            }
+
+            Ok::<_, Box<dyn Error>>(())
+        },
+    )?;
+
+    if args.time && args.format == Format::Text {
+        println!();
+        timing::print_table(&timings);
+    }
+    if args.memory && args.format == Format::Text {
+        println!();
+        memory::print_table(&usages);
+    }
+
+    if args.check {
+        let n_with = |verdict| verdicts.get(&verdict).copied().unwrap_or(0);
+        eprintln!(
+            "check: {} passed, {} failed, {} new",
+            n_with(Verdict::Pass),
+            n_with(Verdict::Fail),
+            n_with(Verdict::New),
+        );
+
+        if args.bless && n_blessed > 0 {
+            answers.save(&answers_path)?;
+            eprintln!(
+                "blessed {n_blessed} answers into `{}`",
+                answers_path.display()
+            );
+        }
+    }
+    let check_failed = !args.bless && verdicts.contains_key(&Verdict::Fail);
+
+    if failed.is_empty() && !check_failed {
+        eprintln!("summary: {n_passed} passed, 0 failed");
+
+        Ok(ExitCode::SUCCESS)
+    } else if failed.is_empty() {
+        eprintln!("summary: {n_passed} passed, 0 failed, but some answers are wrong");
+
+        Ok(ExitCode::FAILURE)
+    } else {
+        let failed_list = failed
+            .iter()
+            .map(|day| format!("{day:02}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!(
+            "summary: {n_passed} passed, {} failed (days {failed_list})",
+            failed.len()
+        );
+
+        Ok(ExitCode::FAILURE)
+    }
 }
@@ -0,0 +1,227 @@
+//! Per-thread allocation accounting, enabled with `alloc-stats` feature.
+//!
+//! Every day is run on a single thread, so per-thread counters give per-day numbers even with
+//! several jobs.
+
+use std::fmt;
+
+use crate::timing::Phase;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Max bytes allocated at once, over the ones allocated before the measurement
+    pub peak_bytes: u64,
+    /// Sum of all allocation sizes
+    pub total_bytes: u64,
+    /// Number of allocations, reallocations included
+    pub allocations: u64,
+}
+
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Runs `f`, counting allocations made by it (on the current thread) if `count` is set.
+///
+/// Returns no stats when built without `alloc-stats` feature.
+pub fn measure<T>(count: bool, f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    if count {
+        let before = counting::start();
+        let result = f();
+
+        return (result, Some(counting::finish(before)));
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    let _ = count;
+
+    (f(), None)
+}
+
+/// Bytes in binary units, e.g. `1.5 MiB`.
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Allocations of a single phase of a day.
+#[derive(Debug)]
+pub struct Usage {
+    pub day: u32,
+    pub phase: Phase,
+    pub stats: AllocStats,
+}
+
+pub fn print_table(usages: &[Usage]) {
+    println!(
+        "{:<6} {:<7} {:>10} {:>10} {:>12}",
+        "day", "phase", "peak", "total", "allocations"
+    );
+
+    for Usage { day, phase, stats } in usages {
+        println!(
+            "{:<6} {:<7} {:>10} {:>10} {:>12}",
+            format!("{day:02}"),
+            phase.to_string(),
+            Bytes(stats.peak_bytes).to_string(),
+            Bytes(stats.total_bytes).to_string(),
+            stats.allocations,
+        );
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::AllocStats;
+
+    #[derive(Debug, Default, Clone, Copy)]
+    struct Counters {
+        current: u64,
+        peak: u64,
+        total: u64,
+        allocations: u64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                current: 0,
+                peak: 0,
+                total: 0,
+                allocations: 0,
+            })
+        };
+    }
+
+    fn update(f: impl FnOnce(&mut Counters)) {
+        // thread locals are not accessible while the thread is being torn down
+        let _ = COUNTERS.try_with(|counters| {
+            let mut value = counters.get();
+            f(&mut value);
+            value.peak = value.peak.max(value.current);
+            counters.set(value);
+        });
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                update(|c| {
+                    c.current += layout.size() as u64;
+                    c.total += layout.size() as u64;
+                    c.allocations += 1;
+                });
+            }
+
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                update(|c| {
+                    c.current += layout.size() as u64;
+                    c.total += layout.size() as u64;
+                    c.allocations += 1;
+                });
+            }
+
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            update(|c| c.current = c.current.saturating_sub(layout.size() as u64));
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                update(|c| {
+                    c.current = c.current.saturating_sub(layout.size() as u64) + new_size as u64;
+                    c.total += new_size as u64;
+                    c.allocations += 1;
+                });
+            }
+
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Resets the peak, returning counters to compare with in [`finish`].
+    pub(super) fn start() -> (u64, u64, u64) {
+        let mut before = (0, 0, 0);
+        update(|c| {
+            c.peak = c.current;
+            before = (c.current, c.total, c.allocations);
+        });
+
+        before
+    }
+
+    pub(super) fn finish((current, total, allocations): (u64, u64, u64)) -> AllocStats {
+        let counters = COUNTERS.with(Cell::get);
+
+        AllocStats {
+            peak_bytes: counters.peak - current,
+            total_bytes: counters.total - total,
+            allocations: counters.allocations - allocations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(0).to_string(), "0 B");
+        assert_eq!(Bytes(1023).to_string(), "1023 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.0 MiB");
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn test_measure() {
+        let (vec, stats) = measure(true, || {
+            let temporary = vec![0u8; 1000];
+            drop(temporary);
+
+            Vec::<u64>::with_capacity(100)
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(vec.capacity(), 100);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.total_bytes, 1800);
+        assert_eq!(stats.peak_bytes, 1000);
+    }
+}
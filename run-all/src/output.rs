@@ -0,0 +1,100 @@
+use std::{
+    error::Error,
+    io::{self, Stdout, Write},
+};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::answers::{self, Verdict};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `day 07: <answer>` lines, as read by humans
+    Text,
+    /// One JSON object per line (JSON Lines)
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+/// Result of solving one part of a day, or failure to do so.
+///
+/// Failures which happen before any part is solved (e.g. missing input) have no `part`.
+/// `status` (`PASS`, `FAIL` or `NEW`) and `expected` are only set with `--check`, allocation
+/// counters only with `--memory`. `answer` is just the value, `description` puts it in a sentence
+/// for the text output only.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    #[serde(skip)]
+    pub description: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub parse_peak_bytes: Option<u64>,
+    pub parse_allocated_bytes: Option<u64>,
+    pub parse_allocations: Option<u64>,
+    pub solve_peak_bytes: Option<u64>,
+    pub solve_allocated_bytes: Option<u64>,
+    pub solve_allocations: Option<u64>,
+    pub error: Option<String>,
+    pub status: Option<Verdict>,
+    pub expected: Option<String>,
+}
+
+pub enum Output {
+    Text,
+    Json(Stdout),
+    Csv(Box<csv::Writer<Stdout>>),
+}
+
+impl Output {
+    pub fn new(format: Format) -> Self {
+        match format {
+            Format::Text => Self::Text,
+            Format::Json => Self::Json(io::stdout()),
+            Format::Csv => Self::Csv(Box::new(csv::Writer::from_writer(io::stdout()))),
+        }
+    }
+
+    pub fn emit(&mut self, record: &Record) -> Result<(), Box<dyn Error>> {
+        match self {
+            Self::Text => {
+                match (&record.answer, record.part, record.status) {
+                    (Some(answer), Some(part), Some(status)) => {
+                        println!("day {:02} part {part}: {status}", record.day);
+                        if status != Verdict::Pass {
+                            let expected = record.expected.as_deref().unwrap_or_default();
+                            print!("{}", answers::diff(expected, answer));
+                        }
+                    }
+                    (Some(answer), _, _) => {
+                        let description = record.description.as_ref().unwrap_or(answer);
+                        println!("day {:02}: {description}", record.day)
+                    }
+                    (None, _, _) => {}
+                }
+                match (&record.error, record.part) {
+                    (Some(error), Some(part)) => {
+                        eprintln!("day {:02} part {part}: error: {error}", record.day)
+                    }
+                    (Some(error), None) => eprintln!("day {:02}: error: {error}", record.day),
+                    (None, _) => {}
+                }
+            }
+            Self::Json(stdout) => {
+                let mut stdout = stdout.lock();
+                serde_json::to_writer(&mut stdout, record)?;
+                writeln!(stdout)?;
+            }
+            Self::Csv(writer) => {
+                writer.serialize(record)?;
+                writer.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Runs `task` for every item on `jobs` threads, passing results to `on_result` on the calling
+/// thread in the order of `items`, each as soon as it and all of the preceding ones are ready.
+///
+/// With a single job everything is done on the calling thread.
+pub fn run_ordered<T, R, E>(
+    items: &[T],
+    jobs: usize,
+    task: impl Fn(&T) -> R + Sync,
+    mut on_result: impl FnMut(R) -> Result<(), E>,
+) -> Result<(), E>
+where
+    T: Sync,
+    R: Send,
+{
+    if jobs <= 1 {
+        return items.iter().try_for_each(|item| on_result(task(item)));
+    }
+
+    let next_item = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next_item, task) = (&next_item, &task);
+
+            scope.spawn(move || loop {
+                let idx = next_item.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(idx) else {
+                    break;
+                };
+
+                if sender.send((idx, task(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_to_report = 0;
+        for (idx, result) in receiver {
+            pending.insert(idx, result);
+
+            while let Some(result) = pending.remove(&next_to_report) {
+                on_result(result)?;
+                next_to_report += 1;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_order_is_kept() {
+        let items: Vec<u64> = (0..20).collect();
+
+        for jobs in [1, 3, 8, 100] {
+            let mut results = vec![];
+            run_ordered(
+                &items,
+                jobs,
+                |&item| {
+                    // make earlier items finish later
+                    thread::sleep(Duration::from_millis(20 - item));
+                    item * 2
+                },
+                |result| {
+                    results.push(result);
+                    Ok::<_, ()>(())
+                },
+            )
+            .unwrap();
+
+            assert_eq!(
+                results,
+                items.iter().map(|item| item * 2).collect::<Vec<_>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use common::{Answer, Error};
+
+use crate::{
+    memory::{AllocStats, Usage},
+    output::Record,
+    timing::{Phase, Stats, Timing},
+};
+
+/// Everything measured while running a single day.
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u32,
+    pub parse_samples: Vec<Duration>,
+    pub parse_memory: Option<AllocStats>,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<Answer, Error>,
+    /// The answer in a sentence, as the day describes it.
+    pub description: Option<String>,
+    pub samples: Vec<Duration>,
+    pub memory: Option<AllocStats>,
+}
+
+impl DayReport {
+    pub fn timings(&self) -> impl Iterator<Item = Timing> + '_ {
+        let parse = Timing {
+            day: self.day,
+            phase: Phase::Parse,
+            samples: self.parse_samples.clone(),
+        };
+        let parts = self.parts.iter().map(|part| Timing {
+            day: self.day,
+            phase: Phase::Part(part.part),
+            samples: part.samples.clone(),
+        });
+
+        [parse].into_iter().chain(parts)
+    }
+
+    /// Allocations of every phase, if they were counted.
+    pub fn memory(&self) -> impl Iterator<Item = Usage> + '_ {
+        let parse = self.parse_memory.map(|stats| Usage {
+            day: self.day,
+            phase: Phase::Parse,
+            stats,
+        });
+        let parts = self.parts.iter().filter_map(|part| {
+            Some(Usage {
+                day: self.day,
+                phase: Phase::Part(part.part),
+                stats: part.memory?,
+            })
+        });
+
+        parse.into_iter().chain(parts)
+    }
+
+    /// Whether solving of any part failed.
+    pub fn has_errors(&self) -> bool {
+        self.parts.iter().any(|part| part.answer.is_err())
+    }
+
+    /// One record per part, solved or failed, with median durations if there were several runs.
+    pub fn records(&self) -> impl Iterator<Item = Record> + '_ {
+        let parse_duration = median(&self.parse_samples);
+
+        self.parts.iter().map(move |part| Record {
+            day: self.day,
+            part: Some(part.part),
+            answer: part.answer.as_ref().ok().map(Answer::to_string),
+            description: part.description.clone(),
+            parse_ns: parse_duration.map(as_nanos),
+            solve_ns: median(&part.samples).map(as_nanos),
+            parse_peak_bytes: self.parse_memory.map(|stats| stats.peak_bytes),
+            parse_allocated_bytes: self.parse_memory.map(|stats| stats.total_bytes),
+            parse_allocations: self.parse_memory.map(|stats| stats.allocations),
+            solve_peak_bytes: part.memory.map(|stats| stats.peak_bytes),
+            solve_allocated_bytes: part.memory.map(|stats| stats.total_bytes),
+            solve_allocations: part.memory.map(|stats| stats.allocations),
+            error: part.answer.as_ref().err().map(Error::to_string),
+            status: None,
+            expected: None,
+        })
+    }
+}
+
+fn median(samples: &[Duration]) -> Option<Duration> {
+    Stats::from_samples(samples).map(|stats| stats.median)
+}
+
+fn as_nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
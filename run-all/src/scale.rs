@@ -0,0 +1,155 @@
+use std::{collections::BTreeSet, error::Error, process::ExitCode, time::Duration};
+
+use clap::Args;
+
+use run_all::{
+    guard,
+    timing::{Phase, Stats},
+};
+
+#[derive(Debug, Args)]
+pub struct ScaleArgs {
+    /// Day to measure
+    day: u32,
+
+    /// Size of the smallest input, in the units of the day's generator
+    #[arg(long)]
+    from: Option<usize>,
+
+    /// How many sizes to try, each twice the previous one
+    #[arg(long, default_value_t = 8)]
+    steps: u32,
+
+    /// How many times to repeat every measurement, the median is reported
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Seed of the generated inputs
+    #[arg(long, default_value_t = 2022)]
+    seed: u64,
+}
+
+/// Exponent `k` of the best fitting `time = c * size^k`, i.e. the slope of the least squares
+/// line on the log-log scale.
+///
+/// Returns `None` for less than two distinct sizes.
+fn growth_exponent(points: &[(usize, Duration)]) -> Option<f64> {
+    let points: Vec<_> = points
+        .iter()
+        .map(|&(size, time)| ((size as f64).ln(), time.as_secs_f64().max(1e-9).ln()))
+        .collect();
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let covariance: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+/// Runs the day on generated inputs of growing sizes, printing median times of every phase and
+/// estimated growth exponents.
+pub fn scale(args: ScaleArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let day =
+        solvers::find(args.day).ok_or_else(|| format!("day {} is not solved yet", args.day))?;
+    let generator = generators::find(args.day)
+        .ok_or_else(|| format!("there is no input generator for day {}", args.day))?;
+
+    let parts: BTreeSet<_> = day.part_numbers().into_iter().collect();
+    let phases: Vec<_> = [Phase::Parse]
+        .into_iter()
+        .chain(parts.iter().map(|&part| Phase::Part(part)))
+        .collect();
+    let from = args.from.unwrap_or(16).max(generator.min_size());
+
+    println!("day {:02}, size is in {}", args.day, generator.unit());
+    print!("{:>10}", "size");
+    for phase in &phases {
+        print!(" {:>12}", phase.to_string());
+    }
+    println!();
+
+    guard::install_panic_hook();
+
+    let mut points = vec![vec![]; phases.len()];
+    for step in 0..args.steps {
+        let size = from << step;
+        let input = generator.generate(size, args.seed);
+
+        // days which have a reader are measured reading, as their largest inputs would be
+        let run = || {
+            if day.has_reader() {
+                let load = || run_all::read(day, &mut input.as_bytes());
+                run_all::run_with(day, load, &parts, args.runs, false)
+            } else {
+                run_all::run_on(day, &input, &parts, args.runs, false)
+            }
+        };
+        let report = match guard::catch(run) {
+            Ok(report) => report,
+            Err(err) => {
+                eprintln!("failed on size {size}: {err}");
+
+                return Ok(ExitCode::FAILURE);
+            }
+        };
+        if let Some(err) = report
+            .parts
+            .iter()
+            .find_map(|part| part.answer.as_ref().err())
+        {
+            eprintln!("failed on size {size}: {err}");
+
+            return Ok(ExitCode::FAILURE);
+        }
+
+        print!("{size:>10}");
+        for (timing, points) in report.timings().zip(&mut points) {
+            let median = Stats::from_samples(&timing.samples)
+                .expect("at least one run is required")
+                .median;
+            print!(" {:>12}", format!("{median:.1?}"));
+            points.push((size, median));
+        }
+        println!();
+    }
+
+    println!();
+    for (phase, points) in phases.iter().zip(&points) {
+        // `Phase` ignores padding, so it's applied to a string
+        let phase = phase.to_string();
+        match growth_exponent(points) {
+            Some(exponent) => println!("{phase:<7} ~ n^{exponent:.2}"),
+            None => println!("{phase:<7} ~ ?"),
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_growth_exponent() {
+        let quadratic: Vec<_> = [10, 20, 40, 80]
+            .into_iter()
+            .map(|size| (size, Duration::from_nanos((size * size) as u64)))
+            .collect();
+        assert!((growth_exponent(&quadratic).unwrap() - 2.0).abs() < 1e-9);
+
+        let constant = [
+            (10, Duration::from_micros(5)),
+            (1000, Duration::from_micros(5)),
+        ];
+        assert!(growth_exponent(&constant).unwrap().abs() < 1e-9);
+
+        assert_eq!(growth_exponent(&[(10, Duration::from_micros(5))]), None);
+    }
+}
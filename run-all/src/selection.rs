@@ -0,0 +1,174 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+/// Command-line selector of days and parts to run.
+///
+/// Supported forms are `7` (all parts of day 7), `9:2` (only part two of day 9),
+/// `3-5` (all parts of days 3 to 5) and `3-5:1` (part one of days 3 to 5).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    days: RangeInclusive<u32>,
+    part: Option<u8>,
+}
+
+impl FromStr for Selector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (days, part) = match s.split_once(':') {
+            Some((days, part)) => (days, Some(part)),
+            None => (s, None),
+        };
+
+        let parse_day = |day: &str| {
+            day.trim()
+                .parse::<u32>()
+                .map_err(|_| format!("invalid day `{day}` in selector `{s}`"))
+        };
+
+        let days = match days.split_once('-') {
+            Some((from, to)) => parse_day(from)?..=parse_day(to)?,
+            None => {
+                let day = parse_day(days)?;
+
+                day..=day
+            }
+        };
+        if days.is_empty() {
+            return Err(format!("empty day range in selector `{s}`"));
+        }
+
+        let part = part
+            .map(|part| match part.trim() {
+                "1" => Ok(1),
+                "2" => Ok(2),
+                _ => Err(format!("invalid part `{part}` in selector `{s}`")),
+            })
+            .transpose()?;
+
+        Ok(Self { days, part })
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.days.start() == self.days.end() {
+            write!(f, "{}", self.days.start())?;
+        } else {
+            write!(f, "{}-{}", self.days.start(), self.days.end())?;
+        }
+
+        match self.part {
+            Some(part) => write!(f, ":{part}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Checks selectors against `available` days (day number → implemented parts) and merges them
+/// into the set of parts to run for each day.
+///
+/// No selectors means everything that is available.
+pub fn resolve(
+    selectors: &[Selector],
+    available: &BTreeMap<u32, Vec<u8>>,
+) -> Result<BTreeMap<u32, BTreeSet<u8>>, String> {
+    if selectors.is_empty() {
+        return Ok(available
+            .iter()
+            .map(|(&day, parts)| (day, parts.iter().copied().collect()))
+            .collect());
+    }
+
+    let mut selected = BTreeMap::<_, BTreeSet<_>>::new();
+    for selector in selectors {
+        for day in selector.days.clone() {
+            let parts = available.get(&day).ok_or_else(|| {
+                format!("day {day} (from selector `{selector}`) is not solved yet")
+            })?;
+
+            match selector.part {
+                Some(part) if !parts.contains(&part) => {
+                    return Err(format!(
+                        "day {day} has no part {part} implemented (from selector `{selector}`)"
+                    ));
+                }
+                Some(part) => {
+                    selected.entry(day).or_default().insert(part);
+                }
+                None => selected.entry(day).or_default().extend(parts),
+            }
+        }
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn available() -> BTreeMap<u32, Vec<u8>> {
+        BTreeMap::from([
+            (1, vec![1, 2]),
+            (2, vec![1, 2]),
+            (3, vec![1, 2]),
+            (9, vec![1]),
+        ])
+    }
+
+    fn selectors(s: &[&str]) -> Vec<Selector> {
+        s.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "7".parse(),
+            Ok(Selector {
+                days: 7..=7,
+                part: None
+            })
+        );
+        assert_eq!(
+            "9:2".parse(),
+            Ok(Selector {
+                days: 9..=9,
+                part: Some(2)
+            })
+        );
+        assert_eq!(
+            "3-5:1".parse(),
+            Ok(Selector {
+                days: 3..=5,
+                part: Some(1)
+            })
+        );
+
+        assert!("".parse::<Selector>().is_err());
+        assert!("5-3".parse::<Selector>().is_err());
+        assert!("7:3".parse::<Selector>().is_err());
+        assert!("a-b".parse::<Selector>().is_err());
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(resolve(&[], &available()).unwrap().len(), 4);
+
+        assert_eq!(
+            resolve(&selectors(&["3", "1-2:2", "1:1"]), &available()),
+            Ok(BTreeMap::from([
+                (1, BTreeSet::from([1, 2])),
+                (2, BTreeSet::from([2])),
+                (3, BTreeSet::from([1, 2])),
+            ]))
+        );
+
+        assert!(resolve(&selectors(&["9:2"]), &available()).is_err());
+        assert!(resolve(&selectors(&["3-9"]), &available()).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use std::{fmt, time::Duration};
+
+/// What exactly was measured for a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    /// `FromStr` for the solution
+    Parse,
+    /// `Solver::solve` for the part
+    Part(u8),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Timing {
+    pub day: u32,
+    pub phase: Phase,
+    pub samples: Vec<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let min = *sorted.first()?;
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            min,
+            mean,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Prints a stats table for every timing, followed by the total over all of them.
+///
+/// Total is computed per run, so every timing is expected to have the same number of samples.
+pub fn print_table(timings: &[Timing]) {
+    println!(
+        "{:<6} {:<7} {:>10} {:>10} {:>10} {:>10}",
+        "day", "phase", "min", "mean", "median", "stddev"
+    );
+
+    let print_row = |day: &str, phase: &str, samples: &[Duration]| {
+        if let Some(Stats {
+            min,
+            mean,
+            median,
+            stddev,
+        }) = Stats::from_samples(samples)
+        {
+            println!(
+                "{:<6} {:<7} {:>10} {:>10} {:>10} {:>10}",
+                day,
+                phase,
+                format!("{min:.1?}"),
+                format!("{mean:.1?}"),
+                format!("{median:.1?}"),
+                format!("{stddev:.1?}"),
+            );
+        }
+    };
+
+    for Timing {
+        day,
+        phase,
+        samples,
+    } in timings
+    {
+        print_row(&format!("{day:02}"), &phase.to_string(), samples);
+    }
+
+    let n_runs = timings.iter().map(|t| t.samples.len()).min().unwrap_or(0);
+    let totals: Vec<Duration> = (0..n_runs)
+        .map(|run| timings.iter().map(|t| t.samples[run]).sum())
+        .collect();
+    print_row("total", "", &totals);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(Stats::from_samples(&[]), None);
+        assert_eq!(
+            Stats::from_samples(&[ms(3)]),
+            Some(Stats {
+                min: ms(3),
+                mean: ms(3),
+                median: ms(3),
+                stddev: Duration::ZERO,
+            })
+        );
+
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(7), ms(4)]).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.mean, ms(4));
+        assert_eq!(stats.median, ms(4));
+        assert_eq!(stats.stddev.as_micros(), 2449);
+    }
+}
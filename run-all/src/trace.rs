@@ -0,0 +1,50 @@
+//! Printing step events of the days run with `--trace`, see `common::trace`.
+
+use common::trace::{self, Event};
+
+/// Runs `f` printing the events of `day` it emits to stderr, only the ones of `steps` unless it's
+/// empty.
+///
+/// An event is printed at once, so events of the days run concurrently don't mix.
+pub fn traced<T>(day: u32, steps: &[String], f: impl FnOnce() -> T) -> T {
+    let steps = steps.to_vec();
+
+    trace::with_sink(
+        move |event| {
+            if matches(&steps, &event) {
+                eprintln!("{}", render(day, &event));
+            }
+        },
+        f,
+    )
+}
+
+fn matches(steps: &[String], event: &Event) -> bool {
+    steps.is_empty() || steps.iter().any(|step| step == event.step)
+}
+
+/// `day 09 move: move=R 4 ...`, with the lines of multi-line values indented.
+fn render(day: u32, event: &Event) -> String {
+    format!("day {day:02} {event}").replace('\n', "\n    ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_and_render() {
+        let event = Event {
+            step: "move",
+            fields: vec![("move", "R 4".to_owned()), ("rope", "H1\n".to_owned())],
+        };
+
+        assert!(matches(&[], &event));
+        assert!(matches(&["cycle".to_owned(), "move".to_owned()], &event));
+        assert!(!matches(&["cycle".to_owned()], &event));
+        assert_eq!(
+            render(9, &event),
+            "day 09 move: move=R 4\n    rope:\n    H1"
+        );
+    }
+}
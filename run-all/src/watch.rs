@@ -0,0 +1,271 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// Day to watch
+    day: u32,
+
+    /// How often to check files for changes, in milliseconds
+    #[arg(long, default_value_t = 500)]
+    interval: u64,
+}
+
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Modification times of all files under `paths` (recursively), missing paths are skipped.
+fn snapshot(paths: &[PathBuf]) -> io::Result<Snapshot> {
+    fn visit(path: &Path, snapshot: &mut Snapshot) -> io::Result<()> {
+        let metadata = match fs::metadata(path) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+
+        if metadata.is_dir() {
+            for entry in fs::read_dir(path)? {
+                visit(&entry?.path(), snapshot)?;
+            }
+        } else {
+            snapshot.insert(path.to_owned(), metadata.modified()?);
+        }
+
+        Ok(())
+    }
+
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        visit(path, &mut snapshot)?;
+    }
+
+    Ok(snapshot)
+}
+
+/// Files added, removed or modified between two snapshots.
+fn changed<'a>(old: &'a Snapshot, new: &'a Snapshot) -> Vec<&'a Path> {
+    let removed = old.keys().filter(|path| !new.contains_key(*path));
+    let added_or_modified = new
+        .iter()
+        .filter(|(path, modified)| old.get(*path) != Some(modified))
+        .map(|(path, _)| path);
+
+    let mut changed: Vec<_> = removed
+        .chain(added_or_modified)
+        .map(PathBuf::as_path)
+        .collect();
+    changed.sort_unstable();
+
+    changed
+}
+
+/// Parts' answers, as reported by `run-all --format json`, or the error.
+type Answers = BTreeMap<u8, Result<String, String>>;
+
+struct Watcher {
+    day: u32,
+    base_path: PathBuf,
+    cargo: String,
+    previous: Answers,
+}
+
+impl Watcher {
+    fn cargo(&self) -> Command {
+        let mut command = Command::new(&self.cargo);
+        command.current_dir(&self.base_path);
+
+        command
+    }
+
+    fn run_tests(&self) -> io::Result<()> {
+        let package = format!("day-{:02}", self.day);
+        println!("running tests of {package}...");
+
+        let output = self
+            .cargo()
+            .args(["test", "--quiet", "-p", &package])
+            .output()?;
+
+        if output.status.success() {
+            println!("tests passed");
+        } else {
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("tests FAILED");
+        }
+
+        Ok(())
+    }
+
+    fn run_solver(&self) -> io::Result<Option<Answers>> {
+        println!("running day {:02}...", self.day);
+
+        let output = self
+            .cargo()
+            .args(["run", "--quiet", "--release", "-p", "run-all", "--"])
+            .args([self.day.to_string().as_str(), "--format", "json"])
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        let mut answers = Answers::new();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let Ok(record) = serde_json::from_str::<serde_json::Value>(line) else {
+                continue;
+            };
+
+            let part = record["part"].as_u64().unwrap_or(0) as u8;
+            let answer = match (record["answer"].as_str(), record["error"].as_str()) {
+                (Some(answer), _) => Ok(answer.to_owned()),
+                (None, error) => Err(error.unwrap_or("unknown error").to_owned()),
+            };
+            answers.insert(part, answer);
+        }
+
+        Ok((!answers.is_empty()).then_some(answers))
+    }
+
+    fn print_answers(&self, answers: &Answers) {
+        for (part, answer) in answers {
+            let label = match part {
+                0 => "error".to_owned(),
+                part => format!("part {part}"),
+            };
+            let previous = self.previous.get(part);
+
+            match answer {
+                Ok(answer) => println!("{label}: {answer}"),
+                Err(error) => println!("{label}: FAILED: {error}"),
+            }
+            match previous {
+                None => {}
+                Some(previous) if previous == answer => println!("  (unchanged)"),
+                Some(Ok(previous)) => println!("  (was: {previous})"),
+                Some(Err(error)) => println!("  (was failing: {error})"),
+            }
+        }
+    }
+
+    fn rerun(&mut self) -> io::Result<()> {
+        self.run_tests()?;
+
+        match self.run_solver()? {
+            Some(answers) => {
+                self.print_answers(&answers);
+                self.previous = answers;
+            }
+            None => println!("no answers, see the build output above"),
+        }
+
+        Ok(())
+    }
+}
+
+/// Re-runs day's tests and solver every time its sources or inputs change, until interrupted.
+pub fn watch(args: WatchArgs) -> Result<ExitCode, Box<dyn Error>> {
+    if solvers::find(args.day).is_none() {
+        return Err(format!("day {} is not solved yet", args.day).into());
+    }
+
+    let base_path = PathBuf::from(env::var("BASE_PATH").unwrap_or_else(|_| ".".to_owned()));
+    let day_dir = base_path.join(format!("day-{:02}", args.day));
+    let watched = ["src", "Cargo.toml", "input.txt", "examples"].map(|path| day_dir.join(path));
+
+    let mut watcher = Watcher {
+        day: args.day,
+        base_path,
+        cargo: env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned()),
+        previous: Answers::new(),
+    };
+
+    println!("watching {}", day_dir.display());
+    let mut last = snapshot(&watched)?;
+    watcher.rerun()?;
+
+    loop {
+        thread::sleep(Duration::from_millis(args.interval));
+
+        let current = snapshot(&watched)?;
+        let changed = changed(&last, &current);
+        if changed.is_empty() {
+            continue;
+        }
+
+        println!();
+        for path in changed {
+            println!("changed: {}", path.display());
+        }
+        watcher.rerun()?;
+
+        last = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+
+        let old = Snapshot::from([("src/lib.rs".into(), t0), ("input.txt".into(), t0)]);
+        let new = Snapshot::from([
+            ("src/lib.rs".into(), t1),
+            ("examples/example.txt".into(), t0),
+        ]);
+
+        assert_eq!(
+            changed(&old, &new),
+            [
+                Path::new("examples/example.txt"),
+                Path::new("input.txt"),
+                Path::new("src/lib.rs")
+            ]
+        );
+        assert!(changed(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let day_dir = env::temp_dir().join(format!("run-all-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&day_dir);
+        fs::create_dir_all(day_dir.join("src/bin")).unwrap();
+        for file in ["src/lib.rs", "src/bin/extra.rs", "input.txt"] {
+            fs::write(day_dir.join(file), "").unwrap();
+        }
+        // `examples` is missing and skipped
+        let watched = ["src", "input.txt", "examples"].map(|path| day_dir.join(path));
+
+        let old = snapshot(&watched).unwrap();
+        let files: Vec<_> = old
+            .keys()
+            .map(|path| path.strip_prefix(&day_dir).unwrap())
+            .collect();
+        assert_eq!(
+            files,
+            ["input.txt", "src/bin/extra.rs", "src/lib.rs"].map(Path::new)
+        );
+
+        let lib = day_dir.join("src/lib.rs");
+        fs::File::options()
+            .write(true)
+            .open(&lib)
+            .unwrap()
+            .set_modified(old[&lib] + Duration::from_secs(1))
+            .unwrap();
+        let new = snapshot(&watched).unwrap();
+        assert_eq!(changed(&old, &new), [lib.as_path()]);
+
+        fs::remove_dir_all(&day_dir).unwrap();
+    }
+}
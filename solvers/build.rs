@@ -0,0 +1,73 @@
+//! Finds all `day-NN` crates of the workspace and generates the registry of their solutions,
+//! along with the type each day is read into if it implements `FromReader`.
+//!
+//! Generated code refers to every such crate, so a day which is not added to `Cargo.toml`
+//! dependencies of this crate fails the build.
+
+use std::{env, error::Error, fs, path::Path};
+
+/// Type of `impl FromReader for Type` in the sources of the day, if there is one.
+fn find_reader(src: &Path) -> Result<Option<String>, Box<dyn Error>> {
+    let mut readers = vec![];
+    for entry in fs::read_dir(src)? {
+        let path = entry?.path();
+        if path.extension() != Some("rs".as_ref()) {
+            continue;
+        }
+
+        for line in fs::read_to_string(&path)?.lines() {
+            if let Some(reader) = line.strip_prefix("impl FromReader for ") {
+                let reader = reader.trim_end_matches(|c: char| c == '{' || c.is_whitespace());
+                readers.push(reader.to_owned());
+            }
+        }
+    }
+
+    match readers.len() {
+        0 | 1 => Ok(readers.pop()),
+        _ => Err(format!("several readers in `{}`: {readers:?}", src.display()).into()),
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR")?;
+    let workspace_dir = Path::new(&manifest_dir).join("..");
+
+    // new workspace member always ends up in the lock file
+    println!("cargo:rerun-if-changed=../Cargo.toml");
+    println!("cargo:rerun-if-changed=../Cargo.lock");
+
+    let mut days = vec![];
+    for entry in fs::read_dir(&workspace_dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(number) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("day-"))
+            .and_then(|number| number.parse::<u32>().ok())
+        else {
+            continue;
+        };
+
+        if entry.path().join("Cargo.toml").is_file() {
+            let src = entry.path().join("src");
+            println!("cargo:rerun-if-changed={}", src.display());
+            days.push((number, find_reader(&src)?));
+        }
+    }
+    days.sort_unstable();
+
+    let entries: String = days
+        .iter()
+        .map(|(number, reader)| match reader {
+            Some(reader) => format!("    {number} => day_{number:02} reads {reader},\n"),
+            None => format!("    {number} => day_{number:02},\n"),
+        })
+        .collect();
+    fs::write(
+        Path::new(&env::var("OUT_DIR")?).join("registry.rs"),
+        format!("registry! {{\n{entries}}}\n"),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+//! Registry of all days solved in the workspace.
+//!
+//! ```
+//! let day = solvers::find(6).expect("day 6 is solved");
+//! let solution = day.parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+//!
+//! assert_eq!(solution.solve(solvers::part(1).unwrap()), "packet starts at 7");
+//! assert_eq!(
+//!     solution.try_solve(solvers::part(1).unwrap()),
+//!     Ok(common::Answer::Number(7))
+//! );
+//!
+//! // failures are told apart with `try_solve`, `solve` only shows them
+//! let solution = day.parse("abcabc").unwrap();
+//! assert_eq!(
+//!     solution.try_solve(solvers::part(1).unwrap()),
+//!     Err(common::Error::unsolvable("couldn't find packet start"))
+//! );
+//!
+//! // some days can also be read piece by piece
+//! let solution = day.from_reader(&mut "mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_bytes());
+//! assert_eq!(
+//!     solution.unwrap().unwrap().try_solve(solvers::part(2).unwrap()),
+//!     Ok(common::Answer::Number(19))
+//! );
+//! ```
+
+use std::{error, io::BufRead, str::FromStr};
+
+use advent_utils::Solver;
+use common::{Answer, Error, FromReader, Part, TrySolve};
+
+/// Object-safe part of [`Solver`] and [`TrySolve`].
+pub trait Solve {
+    /// The answer for display, or the error in its place.
+    fn solve(&self, part: Part) -> String;
+
+    fn try_solve(&self, part: Part) -> Result<Answer, Error>;
+
+    /// The answer in a sentence, for display only.
+    fn describe(&self, part: &Part, answer: &Answer) -> String;
+}
+
+impl<S: Solver + TrySolve> Solve for S {
+    fn solve(&self, part: Part) -> String {
+        Solver::solve(self, part.into())
+    }
+
+    fn try_solve(&self, part: Part) -> Result<Answer, Error> {
+        TrySolve::try_solve(self, part)
+    }
+
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        TrySolve::describe(self, part, answer)
+    }
+}
+
+/// Solution (or a summary of it) read with [`FromReader`], which needn't implement [`Solver`].
+struct Read<T>(T);
+
+impl<T: TrySolve> Solve for Read<T> {
+    fn solve(&self, part: Part) -> String {
+        self.0.solve_or_error(part)
+    }
+
+    fn try_solve(&self, part: Part) -> Result<Answer, Error> {
+        self.0.try_solve(part)
+    }
+
+    fn describe(&self, part: &Part, answer: &Answer) -> String {
+        self.0.describe(part, answer)
+    }
+}
+
+type ParseFn = fn(&str) -> Result<Box<dyn Solve>, Error>;
+type ReadFn = fn(&mut dyn BufRead) -> Result<Box<dyn Solve>, Error>;
+
+/// Registered day, i.e. its `Solution` type erased.
+pub struct Day {
+    day_number: fn() -> u32,
+    implemented_parts: fn() -> Vec<advent_utils::Part>,
+    parse: ParseFn,
+    read: Option<ReadFn>,
+}
+
+impl Day {
+    const fn new<S>(read: Option<ReadFn>) -> Self
+    where
+        S: Solver + TrySolve + FromStr<Err = Box<dyn error::Error>> + 'static,
+    {
+        Self {
+            day_number: S::day_number,
+            implemented_parts: S::implemented_parts,
+            parse: parse::<S>,
+            read,
+        }
+    }
+
+    pub fn number(&self) -> u32 {
+        (self.day_number)()
+    }
+
+    pub fn implemented_parts(&self) -> Vec<Part> {
+        (self.implemented_parts)()
+            .into_iter()
+            .map(Part::from)
+            .collect()
+    }
+
+    /// Numbers (`1` or `2`) of the implemented parts.
+    pub fn part_numbers(&self) -> Vec<u8> {
+        self.implemented_parts().iter().map(part_number).collect()
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Solve>, Error> {
+        (self.parse)(input)
+    }
+
+    /// Whether the day can be read piece by piece with [`Day::from_reader`].
+    pub fn has_reader(&self) -> bool {
+        self.read.is_some()
+    }
+
+    /// Reads the input in memory bounded by its longest line, `None` if the day can't be read
+    /// this way and has to be parsed as a whole.
+    pub fn from_reader(&self, reader: &mut dyn BufRead) -> Option<Result<Box<dyn Solve>, Error>> {
+        self.read.map(|read| read(reader))
+    }
+}
+
+/// Parses the input, recovering [`Error`] boxed by `FromStr` of the day.
+fn parse<S>(input: &str) -> Result<Box<dyn Solve>, Error>
+where
+    S: Solver + TrySolve + FromStr<Err = Box<dyn error::Error>> + 'static,
+{
+    match input.parse::<S>() {
+        Ok(solution) => Ok(Box::new(solution)),
+        Err(err) => match err.downcast::<Error>() {
+            Ok(err) => Err(*err),
+            // every day fails with `Error`, but the type system can't tell
+            Err(err) => Err(Error::invalid_state(err.to_string())),
+        },
+    }
+}
+
+fn read<T: FromReader + TrySolve + 'static>(
+    reader: &mut dyn BufRead,
+) -> Result<Box<dyn Solve>, Error> {
+    Ok(Box::new(Read(T::from_reader(reader)?)))
+}
+
+/// [`ReadFn`] of the type a day is read into, if there is one.
+macro_rules! reader {
+    ($krate: ident) => {
+        None
+    };
+    ($krate: ident, $reader: ident) => {
+        Some(read::<$krate::$reader> as ReadFn)
+    };
+}
+
+macro_rules! registry {
+    ($($number: literal => $krate: ident $(reads $reader: ident)?,)*) => {
+        /// All solved days, ordered by their numbers.
+        pub static DAYS: &[Day] = &[
+            $(Day::new::<$krate::Solution>(reader!($krate $(, $reader)?)),)*
+        ];
+
+        /// Numbers from the crate names, in the same order as [`DAYS`].
+        #[cfg(test)]
+        const CRATE_NUMBERS: &[u32] = &[$($number,)*];
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number() == number)
+}
+
+pub fn part_number(part: &Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+pub fn part(number: u8) -> Option<Part> {
+    match number {
+        1 => Some(Part::One),
+        2 => Some(Part::Two),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_numbers() {
+        assert!(!DAYS.is_empty());
+
+        for (day, &crate_number) in DAYS.iter().zip(CRATE_NUMBERS) {
+            assert_eq!(
+                day.number(),
+                crate_number,
+                "wrong `day_number` of day-{crate_number:02}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let day = find(2).unwrap();
+        let input = "A Y\nB X\nC Z\n";
+        let parsed = day.parse(input).unwrap();
+        let read = day.from_reader(&mut input.as_bytes()).unwrap().unwrap();
+
+        for part in day.implemented_parts() {
+            assert_eq!(read.try_solve(part), parsed.try_solve(part));
+            assert_eq!(read.solve(part), parsed.solve(part));
+        }
+        assert_eq!(
+            day.from_reader(&mut "A Y\nB Q\n".as_bytes())
+                .unwrap()
+                .err()
+                .map(|err| err.to_string()),
+            Some(r#"line 2, column 3: unknown second column: "Q""#.to_owned())
+        );
+
+        let day = find(7).unwrap();
+        assert!(!day.has_reader());
+        assert!(day.from_reader(&mut "$ cd /\n".as_bytes()).is_none());
+    }
+}